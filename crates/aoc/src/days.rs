@@ -0,0 +1,42 @@
+use aoc_core::DynSolution;
+
+/// Every day that has a solution, in calendar order.
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+];
+
+/// Look up the solutions for the requested days, or every day if none were requested.
+pub fn select(days: &[u8]) -> anyhow::Result<Vec<&'static dyn DynSolution>> {
+    if days.is_empty() {
+        return Ok(DAYS.to_vec());
+    }
+
+    days.iter()
+        .map(|&day| {
+            DAYS.iter()
+                .find(|solution| solution.day() == day)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("No solution for day {}", day))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzz() {
+        for &solution in DAYS {
+            if let Some(crash) = aoc_core::fuzz::run(solution, 300, 0) {
+                panic!("{crash}");
+            }
+        }
+    }
+}
@@ -0,0 +1,620 @@
+mod days;
+mod report;
+mod scaffold;
+mod table;
+
+use crate::report::{Format, Record};
+use crate::table::Table;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{bail, Context, Result};
+use aoc_core::answers::{Answers, Verdict};
+use aoc_core::bench::Baseline;
+#[cfg(feature = "fetch")]
+use aoc_core::fetch::{self, Fetcher, UreqClient};
+use aoc_core::fixture::FIXTURE_DIR;
+use aoc_core::input::{self, InputSource};
+use aoc_core::parallel;
+use aoc_core::stream;
+use aoc_core::{ErrorKind, Part, PartOutcome};
+use clap::{Parser, Subcommand};
+
+/// Runs the daily puzzle solutions.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days and print the answers in a table.
+    ///
+    /// Built with the `parallel` feature, days are solved concurrently, so each one's times
+    /// include contention with the others.
+    Run {
+        /// Days to run. Runs every day if none are given.
+        days: Vec<u8>,
+
+        /// Only run this part of each day.
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+
+        /// Read the input from this file, or `-` for stdin. Only valid when running a single day.
+        ///
+        /// Without this, each day's input is looked up in `$AOC_INPUT_DIR`, then the user inputs
+        /// directory, then the day's crate directory.
+        #[arg(long, short)]
+        input: Option<InputSource>,
+
+        /// Output format.
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Read the input one line at a time instead of all at once, keeping memory use bounded.
+        /// Only some days support this.
+        #[arg(long)]
+        stream: bool,
+    },
+    /// Re-solve days and check the answers against each day's recorded `answers.toml`.
+    ///
+    /// Exits with a nonzero status if any answer doesn't match or any day fails to run.
+    Verify {
+        /// Days to verify. Verifies every day if none are given.
+        days: Vec<u8>,
+
+        /// Record the computed answer for every part that has no recorded answer yet.
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and each part of one or more days.
+    ///
+    /// Exits with a nonzero status if any day fails to run, or any stage is slower than the
+    /// baseline by more than the threshold.
+    Bench {
+        /// Days to benchmark. Benchmarks every day if none are given.
+        days: Vec<u8>,
+
+        /// Number of timed runs of each stage.
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+
+        /// Compare against a baseline saved by an earlier run.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the median timings of this run as a baseline.
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+
+        /// Slowdown against the baseline, in percent, beyond which a stage is a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download puzzle inputs into the inputs directory, skipping any that are already there.
+    ///
+    /// Requires the session cookie in `$AOC_SESSION`. Inputs are saved to `$AOC_INPUT_DIR` if set,
+    /// otherwise to the user inputs directory.
+    #[cfg(feature = "fetch")]
+    Fetch {
+        /// Days to fetch. Fetches every day if none are given.
+        days: Vec<u8>,
+
+        /// Year of the event to fetch inputs from.
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Compare days against their simple reference implementations on generated inputs.
+    ///
+    /// Prints the first input each day disagrees on, shrunk to a small reproducer. Exits with a
+    /// nonzero status if any day disagrees.
+    Diff {
+        /// Days to check. Checks every day if none are given.
+        days: Vec<u8>,
+
+        /// Number of inputs to try for each day.
+        #[arg(long, short = 'n', default_value_t = 500)]
+        cases: u64,
+
+        /// Largest size of input to generate, see `generate`.
+        #[arg(long, default_value_t = 20)]
+        max_size: usize,
+    },
+    /// Feed days mutated inputs, looking for any that make parsing or solving panic or hang.
+    ///
+    /// Inputs start from each day's examples and generated inputs. Prints the first crash each
+    /// day has, and exits with a nonzero status if any day crashed.
+    Fuzz {
+        /// Days to fuzz. Fuzzes every day if none are given.
+        days: Vec<u8>,
+
+        /// Number of inputs to try for each day.
+        #[arg(long, short = 'n', default_value_t = 10_000)]
+        iterations: u64,
+
+        /// Seed for the random mutations.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Print a random input for a day, for stress testing its solution.
+    ///
+    /// The same seed always gives the same input.
+    Generate {
+        /// Day to generate an input for.
+        day: u8,
+
+        /// Size of the input. What this counts depends on the day, like lines of input or the
+        /// width of a grid.
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Seed for the random number generator.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Break a day's answer down into the terms it adds up, to find where two answers to the
+    /// same input part ways. Only some days support this.
+    ///
+    /// Each term is ranked by how much it contributes to the answer, from 1 for the largest.
+    Explain {
+        /// Day to explain.
+        day: u8,
+
+        /// Part to explain.
+        #[arg(long, value_parser = parse_part, default_value = "1")]
+        part: Part,
+
+        /// Read the input from this file, or `-` for stdin.
+        #[arg(long, short)]
+        input: Option<InputSource>,
+
+        /// Only list this many of the terms contributing the most, largest first.
+        #[arg(long)]
+        top: Option<usize>,
+
+        /// Print comma-separated values instead of a table.
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Create a crate for a new day from the template and register it with this runner.
+    New {
+        /// Day to create.
+        day: u8,
+
+        /// Title of the day's puzzle, used in the generated docs.
+        #[arg(long, default_value = "TODO")]
+        title: String,
+    },
+}
+
+/// Year of the event these solutions are for.
+#[cfg(feature = "fetch")]
+const YEAR: u16 = 2024;
+
+fn parse_part(arg: &str) -> Result<Part> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => bail!("Part must be 1 or 2"),
+    }
+}
+
+/// Exit status of a command that runs several days.
+///
+/// The first day that fails to run decides the status, see [`ErrorKind::exit_status`]. Otherwise
+/// any failed check, like a wrong answer or a benchmark regression, exits with 1.
+#[derive(Default)]
+struct Status {
+    error: Option<ErrorKind>,
+    check_failed: bool,
+}
+
+impl Status {
+    fn error(&mut self, e: &anyhow::Error) {
+        self.error.get_or_insert_with(|| ErrorKind::of(e));
+    }
+
+    fn check_failed(&mut self) {
+        self.check_failed = true;
+    }
+
+    fn exit_code(&self) -> ExitCode {
+        match (self.error, self.check_failed) {
+            (Some(kind), _) => kind.exit_code(),
+            (None, true) => ExitCode::FAILURE,
+            (None, false) => ExitCode::SUCCESS,
+        }
+    }
+}
+
+fn main() -> Result<ExitCode> {
+    match Cli::parse().command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+            stream,
+        } => run(&days, part, input.as_ref(), format, stream),
+        Command::Verify { days, record } => verify(&days, record),
+        Command::Bench {
+            days,
+            iterations,
+            baseline,
+            save_baseline,
+            threshold,
+        } => bench(
+            &days,
+            iterations,
+            baseline.as_deref(),
+            save_baseline.as_deref(),
+            threshold,
+        ),
+        #[cfg(feature = "fetch")]
+        Command::Fetch { days, year } => fetch(&days, year),
+        Command::Diff {
+            days,
+            cases,
+            max_size,
+        } => diff(&days, cases, max_size),
+        Command::Fuzz {
+            days,
+            iterations,
+            seed,
+        } => fuzz(&days, iterations, seed),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Explain {
+            day,
+            part,
+            input,
+            top,
+            csv,
+        } => explain(day, part, input.as_ref(), top, csv),
+        Command::New { day, title } => new(day, &title),
+    }
+}
+
+fn run(
+    days: &[u8],
+    part: Option<Part>,
+    input: Option<&InputSource>,
+    format: Format,
+    stream: bool,
+) -> Result<ExitCode> {
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let solutions = days::select(days)?;
+    if input.is_some() && solutions.len() != 1 {
+        bail!("--input can only be used when running a single day");
+    }
+    if let Some(solution) = solutions.iter().find(|s| stream && s.streaming().is_none()) {
+        bail!(
+            "Day {:02} can't be streamed, it needs the whole input at once",
+            solution.day()
+        );
+    }
+
+    // Days are independent, so with the `parallel` feature they are solved at the same time
+    let outcomes = parallel::map(&solutions, |solution| {
+        let day = solution.day();
+        match solution.streaming().filter(|_| stream) {
+            Some(streaming) => input::open(day, input, &solution.input_path())
+                .and_then(|mut reader| streaming(&mut reader, &parts)),
+            None => input::load(day, input, &solution.input_path())
+                .and_then(|raw_input| solution.solve(&raw_input, &parts)),
+        }
+    });
+
+    let mut status = Status::default();
+    let mut records = Vec::new();
+    for (solution, outcomes) in solutions.iter().zip(outcomes) {
+        let day = solution.day();
+        match outcomes {
+            Ok(outcomes) => {
+                for outcome in &outcomes {
+                    if let Err(e) = &outcome.answer {
+                        status.error(e);
+                    }
+                    records.push(Record::from_outcome(day, outcome));
+                }
+            }
+            Err(e) => {
+                status.error(&e);
+                records.push(Record::from_error(day, &e));
+            }
+        }
+    }
+
+    report::print(&records, format)?;
+    if format == Format::Table {
+        if let Some(peak) = stream::peak_memory() {
+            println!("Peak memory: {:.1} MiB", peak as f64 / (1024.0 * 1024.0));
+        }
+    }
+    Ok(status.exit_code())
+}
+
+fn verify(days: &[u8], record: bool) -> Result<ExitCode> {
+    let mut status = Status::default();
+
+    let solutions = days::select(days)?;
+    let results = parallel::map(&solutions, |solution| {
+        input::load(solution.day(), None, &solution.input_path())
+            .and_then(|raw_input| solution.solve(&raw_input, &Part::ALL))
+    });
+
+    let mut table = Table::new(["Day", "Part", "Result", "Answer", "Expected"]);
+    for (solution, results) in solutions.iter().zip(results) {
+        let day = format!("{:02}", solution.day());
+        let answers_path = solution.answers_path();
+        let answers_and_results =
+            Answers::load(&answers_path).and_then(|answers| Ok((answers, results?)));
+
+        let (mut answers, results) = match answers_and_results {
+            Ok(answers_and_results) => answers_and_results,
+            Err(e) => {
+                status.error(&e);
+                table.push([day, "-".to_string(), "error".to_string(), format!("{e:#}")]);
+                continue;
+            }
+        };
+
+        let mut recorded = false;
+        for PartOutcome { part, answer, .. } in results {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    status.error(&e);
+                    table.push([&day, &part.to_string(), "error", &format!("{e:#}")]);
+                    continue;
+                }
+            };
+
+            let verdict = answers.check(part, &answer);
+            let expected = match &verdict {
+                Verdict::Pass => answer.clone(),
+                Verdict::Fail { expected } => {
+                    status.check_failed();
+                    expected.clone()
+                }
+                Verdict::Unknown if record => {
+                    answers.set(part, answer.clone());
+                    recorded = true;
+                    "(recorded)".to_string()
+                }
+                Verdict::Unknown => String::new(),
+            };
+            table.push([
+                &day,
+                &part.to_string(),
+                &verdict.to_string(),
+                &answer,
+                &expected,
+            ]);
+        }
+
+        if recorded {
+            if let Err(e) = answers.save(&answers_path) {
+                status.error(&e);
+                table.push([day, "-".to_string(), "error".to_string(), format!("{e:#}")]);
+            }
+        }
+    }
+
+    print!("{table}");
+    Ok(status.exit_code())
+}
+
+fn bench(
+    days: &[u8],
+    iterations: usize,
+    baseline: Option<&Path>,
+    save_baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<ExitCode> {
+    let baseline = baseline.map(Baseline::load).transpose()?;
+    let mut new_baseline = Baseline::default();
+    let mut status = Status::default();
+
+    let mut table = Table::new(["Day", "Stage", "Min", "Median", "Mean", "Stddev", "Change"]);
+    for solution in days::select(days)? {
+        let day = solution.day();
+        let timings = input::load(day, None, &solution.input_path())
+            .and_then(|raw_input| solution.bench(&raw_input, iterations));
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(e) => {
+                status.error(&e);
+                table.push([
+                    format!("{day:02}"),
+                    "-".to_string(),
+                    format!("ERROR: {e:#}"),
+                ]);
+                continue;
+            }
+        };
+
+        for (stage, stats) in timings {
+            new_baseline.insert(day, stage, stats.median);
+
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.change(day, stage, stats.median));
+            let change = match change {
+                Some(change) if change * 100.0 > threshold => {
+                    status.check_failed();
+                    format!("{:+.1}% REGRESSION", change * 100.0)
+                }
+                Some(change) => format!("{:+.1}%", change * 100.0),
+                None => String::new(),
+            };
+
+            table.push([
+                format!("{day:02}"),
+                stage.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+                change,
+            ]);
+        }
+    }
+
+    print!("{table}");
+    if let Some(path) = save_baseline {
+        new_baseline.save(path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    Ok(status.exit_code())
+}
+
+#[cfg(feature = "fetch")]
+fn fetch(days: &[u8], year: u16) -> Result<ExitCode> {
+    let session = std::env::var(fetch::SESSION_VAR)
+        .with_context(|| format!("${} must be set to fetch inputs", fetch::SESSION_VAR))?;
+    let cache_dir = std::env::var_os(input::INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .or_else(input::user_input_dir)
+        .context("Could not determine the inputs directory, set $AOC_INPUT_DIR")?;
+    let fetcher = Fetcher::new(UreqClient::new(), session, cache_dir);
+
+    for solution in days::select(days)? {
+        let day = solution.day();
+        if fetcher.is_cached(day) {
+            println!(
+                "Day {day:02}: already cached at {}",
+                fetcher.cache_path(day).display()
+            );
+            continue;
+        }
+        fetcher.fetch(year, day)?;
+        println!(
+            "Day {day:02}: saved to {}",
+            fetcher.cache_path(day).display()
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn diff(days: &[u8], cases: u64, max_size: usize) -> Result<ExitCode> {
+    if max_size == 0 {
+        bail!("--max-size must be at least 1");
+    }
+
+    let mut status = Status::default();
+    for solution in days::select(days)? {
+        let day = solution.day();
+        let Some(differential) = solution.differential() else {
+            println!("Day {day:02}: no reference implementation");
+            continue;
+        };
+
+        match differential(0..cases, max_size) {
+            Some(divergence) => {
+                status.check_failed();
+                println!("{divergence}");
+            }
+            None => println!("Day {day:02}: matches the reference on {cases} inputs"),
+        }
+    }
+
+    Ok(status.exit_code())
+}
+
+fn fuzz(days: &[u8], iterations: u64, seed: u64) -> Result<ExitCode> {
+    // Panics are caught and reported once per day, not printed as they happen
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut status = Status::default();
+    for solution in days::select(days)? {
+        match aoc_core::fuzz::run(solution, iterations, seed) {
+            Some(crash) => {
+                status.check_failed();
+                println!("{crash}");
+            }
+            None => println!(
+                "Day {:02}: no crashes in {iterations} inputs",
+                solution.day()
+            ),
+        }
+    }
+
+    let _ = std::panic::take_hook();
+    Ok(status.exit_code())
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> Result<ExitCode> {
+    let solution = days::select(&[day])?[0];
+    let generator = solution
+        .generator()
+        .with_context(|| format!("Day {day:02} has no input generator"))?;
+    print!("{}", generator(size, seed));
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn explain(
+    day: u8,
+    part: Part,
+    input: Option<&InputSource>,
+    top: Option<usize>,
+    csv: bool,
+) -> Result<ExitCode> {
+    let solution = days::select(&[day])?[0];
+    let explain = solution
+        .explain()
+        .with_context(|| format!("Day {day:02} can't explain its answers"))?;
+    let raw_input = input::load(day, input, &solution.input_path())?;
+    let explanation = explain(&raw_input, part)?;
+
+    // Ranked rows first if only the top few are wanted, otherwise every row in order
+    let rows: Vec<(usize, &[String])> = match top {
+        Some(k) => (1..).zip(explanation.top(k)).collect(),
+        None => explanation
+            .ranks()
+            .into_iter()
+            .zip(explanation.rows.iter().map(Vec::as_slice))
+            .collect(),
+    };
+    let header = std::iter::once("Rank").chain(explanation.header.iter().map(String::as_str));
+    let cells = |(rank, row): (usize, &[String])| -> Vec<String> {
+        std::iter::once(rank.to_string())
+            .chain(row.iter().cloned())
+            .collect()
+    };
+
+    if csv {
+        println!("{}", report::csv_line(header));
+        for row in rows {
+            println!("{}", report::csv_line(cells(row)));
+        }
+    } else {
+        let mut table = Table::new(header);
+        for row in rows {
+            table.push(cells(row));
+        }
+        print!("{table}");
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new(day: u8, title: &str) -> Result<ExitCode> {
+    let crate_dir = scaffold::new_day(&scaffold::crates_dir(), day, title)?;
+    println!(
+        "Created {} and registered it with the runner",
+        crate_dir.display()
+    );
+    let fixture_dir = crate_dir.join(FIXTURE_DIR);
+    println!(
+        "Paste the example from the puzzle into {} and its answers into {}, then solve `part_one`",
+        fixture_dir.join("example.txt").display(),
+        fixture_dir.join("example.toml").display()
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,144 @@
+use anyhow::Error;
+use aoc_core::{ErrorKind, PartOutcome};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::table::Table;
+
+/// How the results of a run are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable table.
+    #[default]
+    Table,
+    /// A JSON array with one object per part, or per day if the day failed before solving.
+    Json,
+}
+
+/// An error and the chain of errors that caused it, outermost first.
+#[derive(Serialize)]
+pub struct ErrorReport {
+    /// One of `io`, `parse` or `solve`, see [`ErrorKind`].
+    pub kind: String,
+    pub message: String,
+    pub causes: Vec<String>,
+}
+
+impl From<&Error> for ErrorReport {
+    fn from(e: &Error) -> Self {
+        Self {
+            kind: ErrorKind::of(e).to_string(),
+            message: e.to_string(),
+            causes: e.chain().skip(1).map(|cause| cause.to_string()).collect(),
+        }
+    }
+}
+
+/// Result of running one part of a day, or of a day that failed before any part was solved.
+#[derive(Serialize)]
+pub struct Record {
+    pub day: u8,
+    /// `None` if the day failed before solving, e.g. while reading or parsing its input.
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    /// Time spent solving the part, excluding parsing.
+    pub duration_ns: Option<u64>,
+    pub error: Option<ErrorReport>,
+}
+
+impl Record {
+    pub fn from_outcome(day: u8, outcome: &PartOutcome) -> Self {
+        Self {
+            day,
+            part: Some(outcome.part.number()),
+            answer: outcome.answer.as_ref().ok().cloned(),
+            duration_ns: Some(u64::try_from(outcome.duration.as_nanos()).unwrap_or(u64::MAX)),
+            error: outcome.answer.as_ref().err().map(ErrorReport::from),
+        }
+    }
+
+    pub fn from_error(day: u8, e: &Error) -> Self {
+        Self {
+            day,
+            part: None,
+            answer: None,
+            duration_ns: None,
+            error: Some(ErrorReport::from(e)),
+        }
+    }
+}
+
+/// Print records in the given format.
+pub fn print(records: &[Record], format: Format) -> anyhow::Result<()> {
+    match format {
+        Format::Table => {
+            let mut table = Table::new(["Day", "Part", "Answer", "Time"]);
+            for record in records {
+                let answer = match (&record.answer, &record.error) {
+                    (Some(answer), _) => answer.clone(),
+                    (None, Some(e)) => {
+                        let mut cell = format!("ERROR: {}", e.message);
+                        for cause in &e.causes {
+                            cell.push_str(&format!(": {cause}"));
+                        }
+                        cell
+                    }
+                    (None, None) => String::new(),
+                };
+                table.push([
+                    format!("{:02}", record.day),
+                    record.part.map_or("-".to_string(), |part| part.to_string()),
+                    answer,
+                    record.duration_ns.map_or(String::new(), |nanos| {
+                        format!("{:.2?}", std::time::Duration::from_nanos(nanos))
+                    }),
+                ]);
+            }
+            print!("{table}");
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(records)?),
+    }
+    Ok(())
+}
+
+/// One line of comma-separated values, quoting any cell with a comma, quote or line break.
+pub fn csv_line<S: AsRef<str>>(cells: impl IntoIterator<Item = S>) -> String {
+    cells
+        .into_iter()
+        .map(|cell| {
+            let cell = cell.as_ref();
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Context;
+
+    #[test]
+    fn test_error_report_chain() {
+        let e = "x".parse::<u8>().context("Bad number").unwrap_err();
+
+        let report = ErrorReport::from(&e);
+        assert_eq!("solve", report.kind);
+        assert_eq!("Bad number", report.message);
+        assert_eq!(
+            vec!["invalid digit found in string".to_string()],
+            report.causes
+        );
+    }
+
+    #[test]
+    fn test_csv_line() {
+        assert_eq!("1,a b,", csv_line(["1", "a b", ""]));
+        assert_eq!(r#""x,y","say ""hi""""#, csv_line(["x,y", r#"say "hi""#]));
+    }
+}
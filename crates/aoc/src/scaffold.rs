@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::fixture::FIXTURE_DIR;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.tmpl");
+const EXAMPLE_TEMPLATE: &str = include_str!("../templates/day/example.toml.tmpl");
+
+/// Directory containing every crate of the workspace, this runner's included.
+pub fn crates_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate should be inside the crates directory")
+        .to_path_buf()
+}
+
+/// Create the crate for `day` in `crates_dir` from the template and register it with the runner.
+///
+/// Returns the path of the new crate.
+pub fn new_day(crates_dir: &Path, day: u8, title: &str) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25, got {day}");
+    }
+
+    let crate_dir = crates_dir.join(format!("day_{day:02}"));
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+
+    let render = |template: &str| {
+        template
+            .replace("{{DAY_PADDED}}", &format!("{day:02}"))
+            .replace("{{DAY}}", &day.to_string())
+            .replace("{{TITLE}}", title)
+    };
+    let src_dir = crate_dir.join("src");
+    let fixture_dir = crate_dir.join(FIXTURE_DIR);
+    for dir in [&src_dir, &fixture_dir] {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    for (path, template) in [
+        (crate_dir.join("Cargo.toml"), CARGO_TEMPLATE),
+        (src_dir.join("lib.rs"), LIB_TEMPLATE),
+        (src_dir.join("main.rs"), MAIN_TEMPLATE),
+        (fixture_dir.join("example.txt"), ""),
+        (fixture_dir.join("example.toml"), EXAMPLE_TEMPLATE),
+    ] {
+        fs::write(&path, render(template))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    let runner_dir = crates_dir.join("aoc");
+    register(
+        &runner_dir.join("Cargo.toml"),
+        day,
+        &format!("day_{day:02} = {{ path = \"../day_{day:02}\" }}"),
+        |line| line.strip_prefix("day_")?.split_once(' ')?.0.parse().ok(),
+    )?;
+    register(
+        &runner_dir.join("src").join("days.rs"),
+        day,
+        &format!("    &day_{day:02}::Day{day:02},"),
+        |line| {
+            line.trim()
+                .strip_prefix("&day_")?
+                .split_once("::")?
+                .0
+                .parse()
+                .ok()
+        },
+    )?;
+
+    Ok(crate_dir)
+}
+
+/// Insert `entry` into the block of per-day lines in the file at `path`, keeping it in day order.
+///
+/// `day_of` recognises the lines of the block and extracts their day.
+fn register(path: &Path, day: u8, entry: &str, day_of: impl Fn(&str) -> Option<u8>) -> Result<()> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, day_of(line)?)))
+        .collect();
+    if days.iter().any(|&(_, registered)| registered == day) {
+        return Ok(());
+    }
+    let idx = match days.iter().find(|&&(_, registered)| registered > day) {
+        Some(&(idx, _)) => idx,
+        None => match days.last() {
+            Some(&(idx, _)) => idx + 1,
+            None => bail!(
+                "Could not find where to register days in {}",
+                path.display()
+            ),
+        },
+    };
+
+    lines.insert(idx, entry);
+    fs::write(path, lines.join("\n") + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let crates_dir = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let runner_src = crates_dir.join("aoc").join("src");
+        fs::create_dir_all(&runner_src).unwrap();
+        fs::write(
+            crates_dir.join("aoc").join("Cargo.toml"),
+            "[dependencies]\nanyhow = { workspace = true }\nday_01 = { path = \"../day_01\" }\nday_09 = { path = \"../day_09\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            runner_src.join("days.rs"),
+            "pub static DAYS: &[&dyn DynSolution] = &[\n    &day_01::Day01,\n    &day_09::Day09,\n];\n",
+        )
+        .unwrap();
+
+        let crate_dir = new_day(&crates_dir, 8, "Resonant Collinearity").unwrap();
+        let lib = fs::read_to_string(crate_dir.join("src").join("lib.rs")).unwrap();
+        let example = crate_dir.join("fixtures").join("example.txt").exists();
+        let manifest = fs::read_to_string(crates_dir.join("aoc").join("Cargo.toml")).unwrap();
+        let days = fs::read_to_string(runner_src.join("days.rs")).unwrap();
+        let again = new_day(&crates_dir, 8, "");
+        fs::remove_dir_all(&crates_dir).unwrap();
+
+        assert!(lib.contains("/// Day 8: Resonant Collinearity\npub struct Day08;"));
+        assert!(lib.contains("const DAY: u8 = 8;"));
+        assert!(example);
+        assert!(manifest.contains(
+            "day_01 = { path = \"../day_01\" }\nday_08 = { path = \"../day_08\" }\nday_09"
+        ));
+        assert!(days.contains("    &day_01::Day01,\n    &day_08::Day08,\n    &day_09::Day09,\n"));
+        assert!(again.is_err());
+    }
+}
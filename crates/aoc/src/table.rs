@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// A plain-text table with left-aligned, space-padded columns.
+///
+/// Cells may span several lines; the rest of the row is left blank on the extra lines.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<H: ToString>(header: impl IntoIterator<Item = H>) -> Self {
+        Self {
+            header: header.into_iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push<C: ToString>(&mut self, row: impl IntoIterator<Item = C>) {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let widths: Vec<usize> = (0..self.header.len())
+            .map(|col| {
+                std::iter::once(&self.header)
+                    .chain(&self.rows)
+                    .filter_map(|row| row.get(col))
+                    .flat_map(|cell| cell.lines())
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let write_row = |f: &mut Formatter<'_>, row: &[String]| -> FmtResult {
+            let height = row
+                .iter()
+                .map(|cell| cell.lines().count())
+                .max()
+                .unwrap_or(0);
+            for i in 0..height.max(1) {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell.lines().nth(i).unwrap_or("")))
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                writeln!(f, "{}", line.trim_end())?;
+            }
+            Ok(())
+        };
+
+        write_row(f, &self.header)?;
+        let rule: Vec<_> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        write_row(f, &rule)?;
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use toml::{Table, Value};
+
+use crate::Part;
+
+/// Name of the file holding a day's recorded answers, kept next to the day's bundled input.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known correct answers for a day, as recorded in its [`ANSWERS_FILE`]:
+///
+/// ```toml
+/// part_one = 11
+/// part_two = "31"
+/// ```
+///
+/// Either part may be missing if its answer isn't known yet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Outcome of checking a computed answer against the recorded one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer has been recorded for this part.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Pass => "pass",
+                Self::Fail { .. } => "FAIL",
+                Self::Unknown => "unknown",
+            }
+        )
+    }
+}
+
+impl Answers {
+    /// Load recorded answers from `path`. A missing file means no answers are known.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw)
+                .with_context(|| format!("Failed to parse answers from {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to read answers from {}", path.display()))
+            }
+        }
+    }
+
+    /// Parse answers from TOML. Answers may be written as strings or integers.
+    pub fn parse(raw: &str) -> Result<Self> {
+        let table: Table = raw.parse()?;
+
+        let get = |key: &str| -> Result<Option<String>> {
+            match table.get(key) {
+                None => Ok(None),
+                Some(Value::String(answer)) => Ok(Some(answer.clone())),
+                Some(Value::Integer(answer)) => Ok(Some(answer.to_string())),
+                Some(other) => bail!("`{key}` should be a string or integer, found {other}"),
+            }
+        };
+
+        Ok(Self {
+            part_one: get("part_one")?,
+            part_two: get("part_two")?,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut table = Table::new();
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                table.insert(key.to_string(), Value::String(answer.clone()));
+            }
+        }
+
+        std::fs::write(path, table.to_string())
+            .with_context(|| format!("Failed to write answers to {}", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: impl Into<String>) {
+        let answer = Some(answer.into());
+        match part {
+            Part::One => self.part_one = answer,
+            Part::Two => self.part_two = answer,
+        }
+    }
+
+    /// Compare a computed answer with the recorded one for `part`.
+    pub fn check(&self, part: Part, actual: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let expected = Answers {
+            part_one: Some("11".to_string()),
+            part_two: Some("31".to_string()),
+        };
+
+        let actual = Answers::parse("part_one = 11\npart_two = \"31\"\n").unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_rejects_other_types() {
+        assert!(Answers::parse("part_one = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part_one = 11").unwrap();
+
+        assert_eq!(Verdict::Pass, answers.check(Part::One, "11"));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "11".to_string()
+            },
+            answers.check(Part::One, "12")
+        );
+        assert_eq!(Verdict::Unknown, answers.check(Part::Two, "31"));
+    }
+}
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use toml::{Table, Value};
+
+use crate::{Part, Solution, StageError};
+
+/// A separately timed step of solving a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    pub const ALL: [Self; 3] = [Self::Parse, Self::PartOne, Self::PartTwo];
+
+    /// Key used for this stage in a [`Baseline`] file.
+    const fn key(&self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::PartOne => "part_one",
+            Self::PartTwo => "part_two",
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Parse => "parse",
+                Self::PartOne => "part one",
+                Self::PartTwo => "part two",
+            }
+        )
+    }
+}
+
+/// Summary statistics over the timings of repeated runs of a stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Stats need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Time `f` over `iterations` runs, after one untimed warm-up run.
+pub fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    black_box(f()?);
+
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f()?);
+            Ok(start.elapsed())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Stats::from_samples(&samples))
+}
+
+/// Time parsing and each part of a solution separately, each over `iterations` runs.
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> Result<Vec<(Stage, Stats)>> {
+    if iterations == 0 {
+        bail!("Benchmarks need at least one iteration");
+    }
+
+    let parse = time(iterations, || S::parse(input)).context(StageError::parse(S::DAY))?;
+    let parsed = S::parse(input).context(StageError::parse(S::DAY))?;
+    let part_one = time(iterations, || {
+        S::part_one(&parsed).map(|ans| ans.to_string())
+    })
+    .context(StageError::solve(S::DAY, Part::One))?;
+    let part_two = time(iterations, || {
+        S::part_two(&parsed).map(|ans| ans.to_string())
+    })
+    .context(StageError::solve(S::DAY, Part::Two))?;
+
+    Ok(vec![
+        (Stage::Parse, parse),
+        (Stage::PartOne, part_one),
+        (Stage::PartTwo, part_two),
+    ])
+}
+
+/// Median timings of an earlier benchmark run, to compare later runs against.
+///
+/// Stored as TOML with one table per day and the median of each stage in nanoseconds:
+///
+/// ```toml
+/// [day_01]
+/// parse = 152000
+/// part_one = 48000
+/// part_two = 61000
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<u8, BTreeMap<Stage, Duration>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline from {}", path.display()))?;
+        Self::parse(&raw).with_context(|| format!("Failed to parse baseline {}", path.display()))
+    }
+
+    pub fn parse(raw: &str) -> Result<Self> {
+        let table: Table = raw.parse()?;
+
+        let mut baseline = Self::default();
+        for (key, stages) in table {
+            let day = key
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .with_context(|| format!("`{key}` is not a day, expected e.g. `day_01`"))?;
+            let Value::Table(stages) = stages else {
+                bail!("`{key}` should be a table of stage timings");
+            };
+
+            for stage in Stage::ALL {
+                match stages.get(stage.key()) {
+                    None => {}
+                    Some(Value::Integer(nanos)) if *nanos >= 0 => {
+                        baseline.insert(day, stage, Duration::from_nanos(*nanos as u64));
+                    }
+                    Some(other) => bail!(
+                        "`{key}.{}` should be a non-negative integer, found {other}",
+                        stage.key()
+                    ),
+                }
+            }
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let table: Table = self
+            .0
+            .iter()
+            .map(|(day, stages)| {
+                let stages: Table = stages
+                    .iter()
+                    .map(|(stage, median)| {
+                        let nanos = i64::try_from(median.as_nanos()).unwrap_or(i64::MAX);
+                        (stage.key().to_string(), Value::Integer(nanos))
+                    })
+                    .collect();
+                (format!("day_{day:02}"), Value::Table(stages))
+            })
+            .collect();
+
+        std::fs::write(path, table.to_string())
+            .with_context(|| format!("Failed to write baseline to {}", path.display()))
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.0.entry(day).or_default().insert(stage, median);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.0.get(&day)?.get(&stage).copied()
+    }
+
+    /// Relative change of `median` against the baseline, e.g. `0.25` for 25% slower.
+    pub fn change(&self, day: u8, stage: Stage, median: Duration) -> Option<f64> {
+        let baseline = self.get(day, stage)?.as_secs_f64();
+        (baseline > 0.0).then(|| median.as_secs_f64() / baseline - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(4, stats.iterations);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_micros(2500), stats.median);
+        assert_eq!(Duration::from_micros(2500), stats.mean);
+        // sqrt(1.25) ms
+        assert_eq!(1118, stats.stddev.as_micros());
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(1, Stage::Parse, Duration::from_nanos(1500));
+        baseline.insert(7, Stage::PartTwo, Duration::from_millis(20));
+
+        let path = std::env::temp_dir().join(format!("aoc_baseline_{}.toml", std::process::id()));
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(baseline, loaded);
+    }
+
+    #[test]
+    fn test_baseline_change() {
+        let baseline = Baseline::parse("[day_06]\npart_two = 1000").unwrap();
+
+        let change = baseline
+            .change(6, Stage::PartTwo, Duration::from_nanos(1250))
+            .unwrap();
+        assert!((change - 0.25).abs() < 1e-9);
+        assert_eq!(
+            None,
+            baseline.change(6, Stage::PartOne, Duration::from_nanos(1))
+        );
+    }
+}
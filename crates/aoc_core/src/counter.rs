@@ -0,0 +1,175 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A multiset: how many times each item has been seen.
+///
+/// Items that were never added, or whose count drops to zero, are not stored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Count one more of `item`.
+    pub fn add(&mut self, item: T) {
+        self.add_many(item, 1);
+    }
+
+    /// Count `count` more of `item`.
+    pub fn add_many(&mut self, item: T, count: usize) {
+        if count > 0 {
+            *self.counts.entry(item).or_default() += count;
+        }
+    }
+
+    /// How many of `item` have been counted, zero if none.
+    pub fn count<Q>(&self, item: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of items counted, including repeats.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Number of distinct items counted.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Each distinct item and its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+
+    /// The `n` items with the highest counts, highest first. Items with the same count are
+    /// ordered smallest first.
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)>
+    where
+        T: Ord,
+    {
+        let mut items: Vec<_> = self.iter().collect();
+        items.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        items.truncate(n);
+        items
+    }
+
+    /// Items in both counters, each as many times as in whichever has fewer.
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(item, count)| (item.clone(), count.min(other.count(item))))
+            .collect()
+    }
+
+    /// Items in either counter, each as many times as in whichever has more.
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        let mut union = self.clone();
+        for (item, count) in other.iter() {
+            let extra = count.saturating_sub(self.count(item));
+            union.add_many(item.clone(), extra);
+        }
+        union
+    }
+
+    /// Items in this counter, each as many times as it has more than `other`.
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(item, count)| (item.clone(), count.saturating_sub(other.count(item))))
+            .collect()
+    }
+}
+
+impl<T: Eq + Hash> Default for Counter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+/// Build a counter from items and their counts, adding up the counts of repeated items.
+impl<T: Eq + Hash> FromIterator<(T, usize)> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = (T, usize)>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        for (item, count) in iter {
+            counter.add_many(item, count);
+        }
+        counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        let counter: Counter<_> = "abracadabra".chars().collect();
+
+        assert_eq!(5, counter.count(&'a'));
+        assert_eq!(0, counter.count(&'z'));
+        assert_eq!(11, counter.total());
+        assert_eq!(5, counter.len());
+        assert_eq!(
+            vec![(&'a', 5), (&'b', 2), (&'r', 2)],
+            counter.most_common(3)
+        );
+        assert!(Counter::<char>::default().is_empty());
+    }
+
+    #[test]
+    fn test_count_borrowed() {
+        let counter: Counter<String> = ["x", "y", "x"].map(String::from).into_iter().collect();
+
+        assert_eq!(2, counter.count("x"));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: Counter<_> = "aaabbc".chars().collect();
+        let b: Counter<_> = "abbbd".chars().collect();
+
+        assert_eq!("abb".chars().collect::<Counter<_>>(), a.intersection(&b));
+        assert_eq!("aaabbbcd".chars().collect::<Counter<_>>(), a.union(&b));
+        assert_eq!("aac".chars().collect::<Counter<_>>(), a.difference(&b));
+        assert_eq!("bd".chars().collect::<Counter<_>>(), b.difference(&a));
+    }
+}
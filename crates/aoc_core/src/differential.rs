@@ -0,0 +1,235 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::fuzz::{guarded, Outcome};
+use crate::generate::{self, Generator};
+use crate::{Part, Solution};
+
+/// How long the real solution gets to answer a part before it counts as hanging.
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Compares a day against its reference on generated inputs, see [`Solution::differential`].
+///
+/// [`Solution::differential`]: crate::Solution::differential
+pub type DiffFn = fn(Range<u64>, usize) -> Option<Divergence>;
+
+/// A [`Generator`] with a simple reference implementation of each part, to check the real one
+/// against while optimising it.
+pub trait Reference: Generator {
+    /// Answer to `part` worked out the most straightforward way, however slow.
+    ///
+    /// Should return an error for inputs the puzzle rules out, like a guard patrolling in a loop,
+    /// so they are never reported as reproducers.
+    fn reference(part: Part, raw_input: &str) -> Result<String>;
+}
+
+/// An input on which a part's answer differs from the reference's.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence {
+    pub day: u8,
+    pub part: Part,
+    /// Seed of the generated input the divergence was found in.
+    pub seed: u64,
+    /// Input shrunk from the generated one, which still diverges.
+    pub input: String,
+    pub expected: String,
+    /// The real answer, or its error.
+    pub actual: String,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "Day {:02} part {} differs from the reference on the input from seed {}",
+            self.day, self.part, self.seed
+        )?;
+        writeln!(f, "expected: {}", self.expected)?;
+        writeln!(f, "actual:   {}", self.actual)?;
+        write!(f, "input:\n{}", self.input)
+    }
+}
+
+/// Compare `R` against its reference on the input generated from each of `seeds`.
+///
+/// Inputs cycle through sizes from 1 to `max_size`. Returns the first divergence found, with
+/// its input shrunk by [`minimise`].
+pub fn check<R: Reference + 'static>(seeds: Range<u64>, max_size: usize) -> Option<Divergence> {
+    assert!(max_size > 0, "Inputs need a size of at least 1");
+    for seed in seeds {
+        let size = 1 + (seed % max_size as u64) as usize;
+        let raw_input = generate::input::<R>(size, seed);
+        for part in Part::ALL {
+            if compare::<R>(part, &raw_input).is_none() {
+                continue;
+            }
+
+            let input = minimise(&raw_input, |candidate| {
+                compare::<R>(part, candidate).is_some()
+            });
+            let (expected, actual) =
+                compare::<R>(part, &input).expect("Minimised input should still diverge");
+            return Some(Divergence {
+                day: R::DAY,
+                part,
+                seed,
+                input,
+                expected,
+                actual,
+            });
+        }
+    }
+    None
+}
+
+/// The reference and real answers to `part`, if they differ.
+///
+/// Inputs that don't parse or that the reference rejects never differ.
+fn compare<R: Reference + 'static>(part: Part, raw_input: &str) -> Option<(String, String)> {
+    R::parse(raw_input).ok()?;
+    let expected = R::reference(part, raw_input).ok()?;
+    let actual = solve::<R>(part, raw_input);
+
+    (expected != actual).then_some((expected, actual))
+}
+
+/// The real answer to `part`, or a description of how solving it failed.
+///
+/// A part that takes longer than [`TIMEOUT`] is reported as timing out, see [`guarded`].
+fn solve<S: Solution + 'static>(part: Part, raw_input: &str) -> String {
+    let raw_input = raw_input.to_string();
+    let answer = guarded(TIMEOUT, move || {
+        S::parse(&raw_input).and_then(|input| match part {
+            Part::One => S::part_one(&input).map(|ans| ans.to_string()),
+            Part::Two => S::part_two(&input).map(|ans| ans.to_string()),
+        })
+    });
+
+    match answer {
+        Outcome::Finished(Ok(answer)) => answer,
+        Outcome::Finished(Err(e)) => format!("error: {e:#}"),
+        Outcome::Panicked(message) => format!("panicked: {message}"),
+        Outcome::TimedOut => format!("timed out after {TIMEOUT:?}"),
+    }
+}
+
+/// Shrink `input` as far as possible while `fails` still holds for it, first dropping whole
+/// lines and then characters within each line.
+pub fn minimise(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{line}\n")).collect() };
+
+    let lines = input.lines().map(String::from).collect();
+    let mut lines = shrink(lines, |lines| fails(&join(lines)));
+    for idx in 0..lines.len() {
+        let chars = shrink(lines[idx].chars().collect(), |chars| {
+            let mut candidate = lines.clone();
+            candidate[idx] = chars.iter().collect();
+            fails(&join(&candidate))
+        });
+        lines[idx] = chars.into_iter().collect();
+    }
+    join(&lines)
+}
+
+/// Remove runs of `items` while `fails` still holds, halving the length of the runs tried down
+/// to single items.
+fn shrink<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut run = (items.len() / 2).max(1);
+    loop {
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + run).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if fails(&candidate) {
+                items = candidate;
+            } else {
+                start = end;
+            }
+        }
+
+        if run == 1 {
+            return items;
+        }
+        run /= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::Display;
+    use std::path::PathBuf;
+
+    use anyhow::Context;
+
+    use crate::generate::Rng;
+
+    /// Sums the numbers on each line, but part one forgets any above 90.
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u64>;
+
+        fn input_path() -> PathBuf {
+            PathBuf::new()
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|line| line.parse().context("Not a number"))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().filter(|&&num| num <= 90).sum::<u64>())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().sum::<u64>())
+        }
+    }
+
+    impl Generator for Sums {
+        fn generate(size: usize, rng: &mut Rng) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.range(1..=99)))
+                .collect()
+        }
+    }
+
+    impl Reference for Sums {
+        fn reference(_part: Part, raw_input: &str) -> Result<String> {
+            Ok(Self::parse(raw_input)?.iter().sum::<u64>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_check_minimises_divergence() {
+        let divergence = check::<Sums>(0..100, 20).unwrap();
+
+        assert_eq!(Part::One, divergence.part);
+        let num: u64 = divergence.input.trim_end().parse().unwrap();
+        assert!(num > 90);
+        assert_eq!(num.to_string(), divergence.expected);
+        assert_eq!("0", divergence.actual);
+    }
+
+    #[test]
+    fn test_minimise() {
+        let input = "abc\nd x e\nxyz\n";
+
+        assert_eq!("x\n", minimise(input, |candidate| candidate.contains('x')));
+        assert_eq!(
+            "\n\n",
+            minimise(input, |candidate| candidate.lines().count() >= 2)
+        );
+    }
+}
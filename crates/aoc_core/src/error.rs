@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::process::ExitCode;
+
+use crate::input::InputNotFound;
+use crate::parse::ParseError;
+use crate::Part;
+
+/// Broad cause of a failed run, used to pick the process exit status.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The input could not be found or read.
+    Io,
+    /// The input was read but is not valid for the day.
+    Parse,
+    /// The input parsed but a part could not be solved.
+    Solve,
+}
+
+impl ErrorKind {
+    /// Classify an error by the [`StageError`] context attached to it, falling back to [`Io`]
+    /// for I/O failures, [`Parse`] for a [`ParseError`] and [`Solve`] for anything else.
+    ///
+    /// [`Io`]: Self::Io
+    /// [`Parse`]: Self::Parse
+    /// [`Solve`]: Self::Solve
+    pub fn of(e: &anyhow::Error) -> Self {
+        if let Some(stage) = e.downcast_ref::<StageError>() {
+            return stage.kind;
+        }
+
+        let is_io = e
+            .chain()
+            .any(|cause| cause.is::<std::io::Error>() || cause.is::<InputNotFound>());
+        if is_io {
+            Self::Io
+        } else if e.chain().any(|cause| cause.is::<ParseError>()) {
+            Self::Parse
+        } else {
+            Self::Solve
+        }
+    }
+
+    /// Exit status for this kind of error, following the BSD `sysexits.h` conventions.
+    pub const fn exit_status(&self) -> u8 {
+        match self {
+            // EX_IOERR
+            Self::Io => 74,
+            // EX_DATAERR
+            Self::Parse => 65,
+            // EX_SOFTWARE
+            Self::Solve => 70,
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        ExitCode::from(self.exit_status())
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Io => "io",
+                Self::Parse => "parse",
+                Self::Solve => "solve",
+            }
+        )
+    }
+}
+
+/// Context attached to an error to record which stage of solving a day it came from.
+#[derive(Clone, Copy, Debug)]
+pub struct StageError {
+    pub kind: ErrorKind,
+    pub day: u8,
+    pub part: Option<Part>,
+}
+
+impl StageError {
+    pub const fn parse(day: u8) -> Self {
+        Self {
+            kind: ErrorKind::Parse,
+            day,
+            part: None,
+        }
+    }
+
+    pub const fn solve(day: u8, part: Part) -> Self {
+        Self {
+            kind: ErrorKind::Solve,
+            day,
+            part: Some(part),
+        }
+    }
+}
+
+impl Display for StageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.kind, self.part) {
+            (ErrorKind::Io, _) => write!(f, "Failed to read input for day {:02}", self.day),
+            (ErrorKind::Parse, _) => write!(f, "Failed to parse input for day {:02}", self.day),
+            (ErrorKind::Solve, Some(part)) => {
+                write!(f, "Failed to solve day {:02} part {}", self.day, part)
+            }
+            (ErrorKind::Solve, None) => write!(f, "Failed to solve day {:02}", self.day),
+        }
+    }
+}
+
+impl Error for StageError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::{anyhow, Context};
+
+    #[test]
+    fn test_kind_from_stage_context() {
+        let e = "x"
+            .parse::<u8>()
+            .context("Bad number")
+            .context(StageError::parse(1))
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::Parse, ErrorKind::of(&e));
+        assert_eq!("Failed to parse input for day 01", e.to_string());
+    }
+
+    #[test]
+    fn test_kind_from_io_cause() {
+        let e = std::fs::read_to_string("/nonexistent/input.txt")
+            .context("Failed to read input")
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::Io, ErrorKind::of(&e));
+    }
+
+    #[test]
+    fn test_kind_defaults_to_solve() {
+        assert_eq!(ErrorKind::Solve, ErrorKind::of(&anyhow!("No guard found")));
+    }
+}
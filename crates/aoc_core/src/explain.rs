@@ -0,0 +1,85 @@
+use anyhow::{Context, Result};
+
+use crate::{Part, Solution, StageError};
+
+/// Breaks a day's answer to a part down into its terms, see [`Solution::explain`].
+pub type ExplainFn = fn(&str, Part) -> Result<Explanation>;
+
+/// A [`Solution`] whose answers add up terms that can be listed one by one, to find where two
+/// answers to the same input part ways.
+pub trait Explain: Solution {
+    /// The terms that add up to the answer to `part`.
+    fn explanation(part: Part, input: &Self::Input) -> Result<Explanation>;
+}
+
+/// Parse `raw_input` and break `E`'s answer to `part` down into its terms.
+pub fn explain<E: Explain>(raw_input: &str, part: Part) -> Result<Explanation> {
+    let input = E::parse(raw_input).context(StageError::parse(E::DAY))?;
+    E::explanation(part, &input).context(StageError::solve(E::DAY, part))
+}
+
+/// The terms of an answer, one row of cells each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub header: Vec<String>,
+    /// One row per term, in the order the solution works through them.
+    pub rows: Vec<Vec<String>>,
+    /// Indices into `rows`, from the term contributing the most to the answer to the least.
+    pub ranking: Vec<usize>,
+}
+
+impl Explanation {
+    /// Rows of `terms`, ranked by how much each contributes, largest first. Terms that contribute
+    /// the same keep their order.
+    pub fn new<H: ToString, K: Ord>(
+        header: impl IntoIterator<Item = H>,
+        terms: impl IntoIterator<Item = (Vec<String>, K)>,
+    ) -> Self {
+        let (rows, contributions): (Vec<_>, Vec<_>) = terms.into_iter().unzip();
+        let mut ranking: Vec<usize> = (0..rows.len()).collect();
+        ranking.sort_by(|&a, &b| contributions[b].cmp(&contributions[a]));
+
+        Self {
+            header: header.into_iter().map(|h| h.to_string()).collect(),
+            rows,
+            ranking,
+        }
+    }
+
+    /// Rank of each row, counting from 1 for the largest contributor.
+    pub fn ranks(&self) -> Vec<usize> {
+        let mut ranks = vec![0; self.rows.len()];
+        for (rank, &row) in self.ranking.iter().enumerate() {
+            ranks[row] = rank + 1;
+        }
+        ranks
+    }
+
+    /// The `k` rows contributing the most, largest first.
+    pub fn top(&self, k: usize) -> impl Iterator<Item = &[String]> {
+        self.ranking
+            .iter()
+            .take(k)
+            .map(|&row| self.rows[row].as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranking() {
+        let terms = [("a", 2), ("b", 7), ("c", 2), ("d", 0)]
+            .map(|(name, contribution)| (vec![name.to_string()], contribution));
+        let explanation = Explanation::new(["Name"], terms);
+
+        assert_eq!(vec![1, 0, 2, 3], explanation.ranking);
+        assert_eq!(vec![2, 1, 3, 4], explanation.ranks());
+        assert_eq!(
+            vec![["b".to_string()], ["a".to_string()]],
+            explanation.top(2).collect::<Vec<_>>()
+        );
+        assert_eq!(4, explanation.top(10).count());
+    }
+}
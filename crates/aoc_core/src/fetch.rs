@@ -0,0 +1,308 @@
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+
+use crate::input;
+
+/// Environment variable holding the session cookie used to download inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the site operators can tell where the traffic comes from.
+pub const USER_AGENT: &str = "github.com/moconn68/aoc_2024 input fetcher";
+
+/// Minimum time between two requests made by the same [`Fetcher`].
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// A minimal blocking HTTP client, so the transport can be swapped out in tests.
+pub trait HttpClient {
+    /// Send a GET request with the given headers.
+    ///
+    /// Only transport failures are errors: a response with any status code is returned as is.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// [`HttpClient`] backed by `ureq`.
+#[cfg(feature = "fetch")]
+pub struct UreqClient(ureq::Agent);
+
+#[cfg(feature = "fetch")]
+impl UreqClient {
+    pub fn new() -> Self {
+        Self(ureq::Agent::new())
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Response> {
+        let request = headers
+            .iter()
+            .fold(self.0.get(url), |request, (name, value)| {
+                request.set(name, value)
+            });
+
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e).with_context(|| format!("Request to {url} failed")),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response
+                .into_string()
+                .with_context(|| format!("Failed to read response body from {url}"))?,
+        })
+    }
+}
+
+/// Downloads puzzle inputs and caches them on disk, one file per day.
+///
+/// A cached input is never downloaded again, and requests are spaced at least
+/// [`DEFAULT_MIN_INTERVAL`] apart unless configured otherwise.
+pub struct Fetcher<C> {
+    client: C,
+    session: String,
+    cache_dir: PathBuf,
+    base_url: String,
+    min_interval: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    /// Create a fetcher caching into `cache_dir`, which is laid out like
+    /// [`user_input_dir`](input::user_input_dir) so the runner finds fetched inputs.
+    pub fn new(client: C, session: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client,
+            session: session.into(),
+            cache_dir: cache_dir.into(),
+            base_url: BASE_URL.to_string(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: Cell::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(input::input_file_name(day))
+    }
+
+    /// Whether the input for `day` is already cached.
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.cache_path(day).is_file()
+    }
+
+    /// Return the input for the given day, downloading and caching it if it isn't cached yet.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let cache_path = self.cache_path(day);
+        if cache_path.is_file() {
+            return std::fs::read_to_string(&cache_path)
+                .with_context(|| format!("Failed to read cached input {}", cache_path.display()));
+        }
+
+        let body = self.download(year, day)?;
+        write_cache(&cache_path, &body)?;
+        Ok(body)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String> {
+        self.throttle();
+
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let cookie = format!("session={}", self.session);
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        self.last_request.set(Some(Instant::now()));
+
+        match response.status {
+            200 => Ok(response.body),
+            404 => bail!("Input for {year} day {day} is not available yet"),
+            400 | 401 | 500 => bail!(
+                "Request for {year} day {day} was rejected with status {}, check ${SESSION_VAR}: {}",
+                response.status,
+                response.body.trim()
+            ),
+            status => bail!("Request for {year} day {day} failed with status {status}"),
+        }
+    }
+
+    /// Sleep until at least `min_interval` has passed since the previous request.
+    fn throttle(&self) {
+        if let Some(last) = self.last_request.get() {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                std::thread::sleep(self.min_interval - elapsed);
+            }
+        }
+    }
+}
+
+/// Write through a temporary file so an interrupted download never leaves a partial input behind.
+fn write_cache(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create cache directory {}", dir.display()))?;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, contents)
+        .and_then(|_| std::fs::rename(&tmp_path, path))
+        .with_context(|| format!("Failed to write cached input {}", path.display()))
+}
+
+#[cfg(all(test, feature = "fetch"))]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    /// Request line and cookie header of a request received by the stub server.
+    type Request = (String, Option<String>);
+
+    /// Serves canned responses on a local port and records the request line and cookie of each
+    /// request it receives.
+    struct StubServer {
+        url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl StubServer {
+        fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(&stream);
+
+                    let mut request_line = String::new();
+                    reader.read_line(&mut request_line).unwrap();
+                    let mut cookie = None;
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = header.split_once(':') {
+                            if name.eq_ignore_ascii_case("cookie") {
+                                cookie = Some(value.trim().to_string());
+                            }
+                        }
+                    }
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((request_line.trim().to_string(), cookie));
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            Self { url, requests }
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(server: &StubServer, cache_dir: &Path) -> Fetcher<UreqClient> {
+        Fetcher::new(UreqClient::new(), "abc123", cache_dir)
+            .with_base_url(&server.url)
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let server = StubServer::start(200, "3   4\n4   3\n");
+        let cache_dir = temp_cache_dir("caches");
+        let fetcher = fetcher(&server, &cache_dir);
+
+        assert_eq!("3   4\n4   3\n", fetcher.fetch(2024, 1).unwrap());
+        assert_eq!("3   4\n4   3\n", fetcher.fetch(2024, 1).unwrap());
+        let cached = std::fs::read_to_string(cache_dir.join("day_01.txt")).unwrap();
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!("3   4\n4   3\n", cached);
+        assert_eq!(
+            vec![(
+                "GET /2024/day/1/input HTTP/1.1".to_string(),
+                Some("session=abc123".to_string())
+            )],
+            server.requests()
+        );
+    }
+
+    #[test]
+    fn test_fetch_error_not_cached() {
+        let server = StubServer::start(400, "Puzzle inputs differ by user.");
+        let cache_dir = temp_cache_dir("error");
+        let fetcher = fetcher(&server, &cache_dir);
+
+        let err = fetcher.fetch(2024, 6).unwrap_err();
+
+        assert!(err.to_string().contains("status 400"));
+        assert!(!fetcher.is_cached(6));
+    }
+
+    #[test]
+    fn test_fetch_rate_limited() {
+        let server = StubServer::start(200, "1");
+        let cache_dir = temp_cache_dir("rate");
+        let interval = Duration::from_millis(200);
+        let fetcher = fetcher(&server, &cache_dir).with_min_interval(interval);
+
+        let start = Instant::now();
+        fetcher.fetch(2024, 1).unwrap();
+        fetcher.fetch(2024, 2).unwrap();
+        let elapsed = start.elapsed();
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert!(elapsed >= interval);
+        assert_eq!(2, server.requests().len());
+    }
+}
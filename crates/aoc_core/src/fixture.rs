@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::answers::{Answers, Verdict};
+use crate::{Part, PartOutcome, Solution};
+
+/// Directory in a day's crate holding its example inputs.
+///
+/// Each example is an input file `<name>.txt` with its expected answers next to it in
+/// `<name>.toml`, in the same format as [`crate::answers::ANSWERS_FILE`]. An example may only
+/// have an answer for one of the parts.
+pub const FIXTURE_DIR: &str = "fixtures";
+
+/// Solve the example input at `input_path` and check the answers against the ones recorded
+/// next to it.
+///
+/// Days that can be solved by streaming are checked that way too. Returns an error if the example
+/// has no recorded answers, so a fixture that was never filled in doesn't pass unnoticed.
+pub fn check<S: Solution>(input_path: &Path) -> Result<()> {
+    let answers = Answers::load(&input_path.with_extension("toml"))?;
+    let parts: Vec<_> = Part::ALL
+        .into_iter()
+        .filter(|&part| answers.get(part).is_some())
+        .collect();
+    if parts.is_empty() {
+        bail!("{} has no expected answers", input_path.display());
+    }
+
+    let raw_input = std::fs::read_to_string(input_path)
+        .with_context(|| format!("Failed to read {}", input_path.display()))?;
+    let input = S::parse(&raw_input)?;
+
+    let mut failures = Vec::new();
+    for &part in &parts {
+        let answer = match part {
+            Part::One => S::part_one(&input)?.to_string(),
+            Part::Two => S::part_two(&input)?.to_string(),
+        };
+        if let Verdict::Fail { expected } = answers.check(part, &answer) {
+            failures.push(format!("part {part}: expected {expected}, got {answer}"));
+        }
+    }
+
+    if let Some(streaming) = S::streaming() {
+        let outcomes = streaming(&mut raw_input.as_bytes(), &parts)?;
+        for PartOutcome { part, answer, .. } in outcomes {
+            let answer = answer?;
+            if let Verdict::Fail { expected } = answers.check(part, &answer) {
+                failures.push(format!(
+                    "part {part} streamed: expected {expected}, got {answer}"
+                ));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!("{}:\n{}", input_path.display(), failures.join("\n"));
+    }
+    Ok(())
+}
+
+/// Generate one test per example in the calling crate's [`FIXTURE_DIR`], checking a
+/// [`Solution`] against each with [`check`].
+///
+/// The calling crate needs `rstest` as a dev-dependency. Examples are found when the tests are
+/// compiled, so a newly added example only runs once the crate is rebuilt.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     aoc_core::fixture_tests!(crate::Day01);
+/// }
+/// ```
+#[macro_export]
+macro_rules! fixture_tests {
+    ($solution:ty) => {
+        #[::rstest::rstest]
+        fn fixture(#[files("fixtures/*.txt")] input_path: ::std::path::PathBuf) {
+            if let Err(e) = $crate::fixture::check::<$solution>(&input_path) {
+                panic!("{e:#}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::Display;
+    use std::path::PathBuf;
+
+    /// Sums the numbers on each line, and multiplies them for part two.
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u64>;
+
+        fn input_path() -> PathBuf {
+            PathBuf::new()
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|line| line.parse().context("Not a number"))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().sum::<u64>())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().product::<u64>())
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = std::env::temp_dir().join(format!("aoc_fixture_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("example.txt");
+        std::fs::write(&input_path, "2\n3\n").unwrap();
+        let answers_path = input_path.with_extension("toml");
+
+        let missing = check::<Sums>(&input_path);
+        std::fs::write(&answers_path, "# part_one = 0\n").unwrap();
+        let empty = check::<Sums>(&input_path);
+        std::fs::write(&answers_path, "part_one = 5\n").unwrap();
+        let right = check::<Sums>(&input_path);
+        std::fs::write(&answers_path, "part_one = 5\npart_two = 5\n").unwrap();
+        let wrong = check::<Sums>(&input_path);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(format!("{:#}", missing.unwrap_err()).contains("no expected answers"));
+        assert!(empty.is_err());
+        assert!(right.is_ok());
+        assert!(format!("{:#}", wrong.unwrap_err()).contains("part two: expected 5, got 6"));
+    }
+}
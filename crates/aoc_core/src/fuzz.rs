@@ -0,0 +1,262 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::differential::minimise;
+use crate::fixture::FIXTURE_DIR;
+use crate::generate::Rng;
+use crate::{DynSolution, Part};
+
+/// How long a day gets to parse and solve a fuzzed input before it counts as hanging.
+pub const TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Text spliced into inputs, picked to hit edge cases of the parsers: numbers that don't fit,
+/// separators in the wrong place and characters that aren't ASCII.
+const TOKENS: &[&str] = &[
+    "0",
+    "-1",
+    "255",
+    "256",
+    "65536",
+    "4294967296",
+    "18446744073709551616",
+    "99999999999999999999999",
+    " ",
+    "\t",
+    "\n",
+    "\r\n",
+    "\n\n",
+    ":",
+    ",",
+    "|",
+    "||",
+    "#",
+    "^",
+    ">",
+    "v",
+    "<",
+    ".",
+    "X",
+    "mul(",
+    "mul(999,999)",
+    "do()",
+    "don't()",
+    "é",
+    "٣",
+    "\u{feff}",
+];
+
+/// How running a closure under [`guarded`] went.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    /// The closure panicked with this message.
+    Panicked(String),
+    /// The closure didn't finish in time.
+    TimedOut,
+}
+
+/// Run `f` on its own thread, catching any panic and giving up on it after `timeout`.
+///
+/// A thread that times out can't be stopped, so it is left running in the background.
+pub fn guarded<T, F>(timeout: Duration, f: F) -> Outcome<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // Nobody is listening any more if this took too long
+        let _ = sender.send(f());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(value) => Outcome::Finished(value),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => {
+            let payload = handle
+                .join()
+                .expect_err("Thread should only hang up by panicking");
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string());
+            Outcome::Panicked(message)
+        }
+    }
+}
+
+/// An input that made a day panic or hang.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Crash {
+    pub day: u8,
+    /// The panic message, or `None` if the day hung.
+    pub panic: Option<String>,
+    /// The input, shrunk with [`minimise`] if the day panicked.
+    pub input: String,
+}
+
+impl Display for Crash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.panic {
+            Some(message) => writeln!(f, "Day {:02} panicked: {message}", self.day)?,
+            None => writeln!(f, "Day {:02} took longer than {TIMEOUT:?}", self.day)?,
+        }
+        write!(f, "input:\n{}", self.input)
+    }
+}
+
+/// Parse and solve both parts of mutations of `solution`'s [`corpus`], `iterations` times.
+///
+/// Errors are fine, any input may be invalid. Returns the first input that makes the day panic
+/// or hang instead.
+pub fn run(solution: &'static dyn DynSolution, iterations: u64, seed: u64) -> Option<Crash> {
+    let corpus = corpus(solution);
+    let mut rng = Rng::new(seed);
+
+    for _ in 0..iterations {
+        let mut input = rng.choose(&corpus).clone();
+        for _ in 0..rng.range(1..=4) {
+            input = mutate(&input, &mut rng);
+        }
+
+        match solve(solution, &input) {
+            Outcome::Finished(()) => {}
+            Outcome::Panicked(_) => {
+                let input = minimise(&input, |candidate| {
+                    matches!(solve(solution, candidate), Outcome::Panicked(_))
+                });
+                let Outcome::Panicked(message) = solve(solution, &input) else {
+                    unreachable!("Minimised input should still panic");
+                };
+                return Some(Crash {
+                    day: solution.day(),
+                    panic: Some(message),
+                    input,
+                });
+            }
+            // Shrinking would leave a thread spinning for every candidate that still hangs
+            Outcome::TimedOut => {
+                return Some(Crash {
+                    day: solution.day(),
+                    panic: None,
+                    input,
+                })
+            }
+        }
+    }
+    None
+}
+
+fn solve(solution: &'static dyn DynSolution, input: &str) -> Outcome<()> {
+    let input = input.to_string();
+    guarded(TIMEOUT, move || {
+        // Errors are an expected outcome for fuzzed inputs, so only the work matters
+        let _ = solution.solve(&input, &Part::ALL);
+    })
+}
+
+/// Inputs to start fuzzing `solution` from: the examples in its crate's [`FIXTURE_DIR`], some
+/// small generated inputs if it has a generator, and an empty input.
+pub fn corpus(solution: &dyn DynSolution) -> Vec<String> {
+    let mut corpus = vec![String::new()];
+
+    let fixture_dir = solution.input_path().with_file_name(FIXTURE_DIR);
+    if let Ok(entries) = std::fs::read_dir(fixture_dir) {
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        paths.sort();
+        corpus.extend(
+            paths
+                .iter()
+                .filter_map(|path| std::fs::read_to_string(path).ok()),
+        );
+    }
+
+    if let Some(generator) = solution.generator() {
+        corpus.extend((1..=5).map(|size| generator(size, size as u64)));
+    }
+    corpus
+}
+
+/// Apply a single random edit to `input`.
+fn mutate(input: &str, rng: &mut Rng) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut lines: Vec<&str> = input.lines().collect();
+    // Where in `chars` an edit starts, which may be the very end
+    let at = rng.index(chars.len() + 1);
+
+    match rng.range(0..=6) {
+        0 => {
+            let end = (at + rng.range(1..=8) as usize).min(chars.len());
+            chars[..at].iter().chain(&chars[end..]).collect()
+        }
+        1 => {
+            let token: String = rng.choose(TOKENS).to_string();
+            chars[..at]
+                .iter()
+                .copied()
+                .chain(token.chars())
+                .chain(chars[at..].iter().copied())
+                .collect()
+        }
+        2 if at < chars.len() => {
+            let token: Vec<char> = rng.choose(TOKENS).chars().collect();
+            let mut mutated = chars.clone();
+            mutated[at] = *rng.choose(&token);
+            mutated.into_iter().collect()
+        }
+        3 if !lines.is_empty() => {
+            let line = lines[rng.index(lines.len())];
+            lines.insert(rng.index(lines.len() + 1), line);
+            lines.join("\n")
+        }
+        4 if !lines.is_empty() => {
+            lines.remove(rng.index(lines.len()));
+            lines.join("\n")
+        }
+        5 if !lines.is_empty() => {
+            let (a, b) = (rng.index(lines.len()), rng.index(lines.len()));
+            lines.swap(a, b);
+            lines.join("\n")
+        }
+        _ => chars[..at].iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded() {
+        assert_eq!(Outcome::Finished(4), guarded(TIMEOUT, || 2 + 2));
+        assert_eq!(
+            Outcome::Panicked("Out of cheese".to_string()),
+            guarded(TIMEOUT, || -> u8 { panic!("Out of cheese") })
+        );
+        assert_eq!(
+            Outcome::TimedOut,
+            guarded(Duration::from_millis(10), || thread::sleep(TIMEOUT))
+        );
+    }
+
+    #[test]
+    fn test_mutate_is_seeded() {
+        let mutations = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..20)
+                .map(|_| mutate("12   34\n5   6\n", &mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(mutations(3), mutations(3));
+        assert!(mutations(3)
+            .iter()
+            .any(|mutated| mutated != "12   34\n5   6\n"));
+    }
+}
@@ -0,0 +1,157 @@
+use std::ops::{Range, RangeInclusive};
+
+use anyhow::{bail, Context, Result};
+
+use crate::Solution;
+
+/// Generates a random input for a day, see [`Solution::generator`].
+pub type GenerateFn = fn(usize, u64) -> String;
+
+/// A [`Solution`] that can generate random inputs for itself, for stress testing and comparing
+/// implementations.
+pub trait Generator: Solution {
+    /// Generate an input that [`Solution::parse`] accepts.
+    ///
+    /// What `size` counts is up to the day, like lines of input or the width of a grid.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Generate an input of `size` for `G`, the same one for the same `seed`.
+pub fn input<G: Generator>(size: usize, seed: u64) -> String {
+    G::generate(size, &mut Rng::new(seed))
+}
+
+/// Check that `G` generates the same input for the same seed, and that every seed in `seeds`
+/// gives an input that parses and solves without errors.
+pub fn check<G: Generator>(size: usize, seeds: Range<u64>) -> Result<()> {
+    for seed in seeds {
+        let raw_input = input::<G>(size, seed);
+        if raw_input != input::<G>(size, seed) {
+            bail!("Seed {seed} generated two different inputs");
+        }
+
+        let parsed = G::parse(&raw_input)
+            .with_context(|| format!("Failed to parse input generated from seed {seed}"))?;
+        G::part_one(&parsed)
+            .with_context(|| format!("Failed to solve part one of input from seed {seed}"))?;
+        G::part_two(&parsed)
+            .with_context(|| format!("Failed to solve part two of input from seed {seed}"))?;
+    }
+    Ok(())
+}
+
+/// Generate a test checking that a [`Generator`] makes inputs of `size` that its solution parses
+/// and solves, see [`check`].
+///
+/// With a `reference_size`, also generate a test checking that the solution agrees with its
+/// [`Reference`](crate::differential::Reference) on inputs of up to that size, see
+/// [`differential::check`](crate::differential::check).
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     aoc_core::generator_tests!(crate::Day01, size: 100, reference_size: 20);
+/// }
+/// ```
+#[macro_export]
+macro_rules! generator_tests {
+    ($solution:ty, size: $size:expr) => {
+        #[test]
+        fn test_generated_inputs() {
+            if let Err(e) = $crate::generate::check::<$solution>($size, 0..20) {
+                panic!("{e:#}");
+            }
+        }
+    };
+    ($solution:ty, size: $size:expr, reference_size: $max_size:expr) => {
+        $crate::generator_tests!($solution, size: $size);
+
+        #[test]
+        fn test_matches_reference() {
+            if let Some(divergence) = $crate::differential::check::<$solution>(0..100, $max_size) {
+                panic!("{divergence}");
+            }
+        }
+    };
+}
+
+/// Small seeded pseudo-random number generator (SplitMix64).
+///
+/// Not suitable for anything but generating test inputs, but the same seed gives the same
+/// numbers on every platform and release.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Range {start}..={end} should not be empty");
+        match (end - start).checked_add(1) {
+            Some(span) => start + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a collection of `len` items, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "Can't pick an index of an empty collection");
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// A random item of `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let mut rng = Rng::new(0);
+        let rolls: Vec<_> = (0..1000).map(|_| rng.range(3..=6)).collect();
+
+        assert!(rolls.iter().all(|roll| (3..=6).contains(roll)));
+        assert!((3..=6).all(|face| rolls.contains(&face)));
+        assert_eq!(5, rng.range(5..=5));
+    }
+}
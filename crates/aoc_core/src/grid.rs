@@ -0,0 +1,311 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+
+use crate::parse::{Line, ParseError};
+use crate::point::{Direction, Point};
+
+/// A rectangular 2D grid stored row by row in a single `Vec`.
+///
+/// Cells are addressed by [`Point`], with the origin at the top left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells in row-major order.
+    ///
+    /// Returns an error if there isn't exactly one cell per position, or the grid is too large
+    /// to count its positions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        let Some(size) = width.checked_mul(height) else {
+            bail!("{width}x{height} grid is too large");
+        };
+        if size != cells.len() {
+            bail!(
+                "{}x{} grid needs {} cells, got {}",
+                width,
+                height,
+                size,
+                cells.len()
+            );
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parse a grid from lines of text, converting each character into a cell.
+    ///
+    /// Every line must have the same number of characters. Characters that fail to convert and
+    /// rows of the wrong width are reported as a [`ParseError`].
+    pub fn parse_lines<S: AsRef<str>>(lines: impl IntoIterator<Item = S>) -> Result<Self>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for text in lines {
+            let line = Line::new(height + 1, text.as_ref());
+            let len_before = cells.len();
+            for (idx, c) in line.text.char_indices() {
+                let cell = T::try_from(c).map_err(|e| {
+                    ParseError::new(line.number, line.text, idx..idx + c.len_utf8(), e)
+                })?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - len_before;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line
+                        .error(format!("Row has width {row_width}, expected {width}"))
+                        .into())
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Position one step from `pos` in the given direction, or `None` if that leaves the grid.
+    pub fn step(&self, pos: Point, direction: Direction) -> Option<Point> {
+        let next = pos.step(direction);
+        self.contains(next).then_some(next)
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// In-bounds neighbours of `pos` in the four orthogonal directions.
+    pub fn neighbours_4(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// In-bounds neighbours of `pos` in all eight directions.
+    pub fn neighbours_8(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        pos: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(pos, direction))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Cells from `start` onwards, repeatedly stepping in `direction` until leaving the grid.
+    ///
+    /// Yields nothing if `start` is outside the grid.
+    pub fn walk(&self, start: Point, direction: Direction) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.contains(start).then_some(start), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(|pos| &self[pos])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.walk(Point::new(x as i64, 0), Direction::Down)
+    }
+
+    /// Cells on the diagonal going down and to the right from `start`.
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.walk(start, Direction::DownRight)
+    }
+
+    /// Cells on the diagonal going down and to the left from `start`.
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = &T> {
+        self.walk(start, Direction::DownLeft)
+    }
+
+    /// Index into `cells` of the cell at `pos`, or `None` if it is outside the grid.
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        let (x, y) = pos.to_indices()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+}
+
+/// Panics if `pos` is outside the grid, see [`Grid::get`] for checked access.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos} outside {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos} outside {width}x{height} grid"))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_lines(s.lines())
+    }
+}
+
+/// Renders each row on its own line, with cells written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'f'), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(-1, 0)));
+        assert_eq!(INPUT, grid.to_string());
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Grid::new(2, 3, vec![0; 6]).is_ok());
+        assert!(Grid::new(2, 3, vec![0; 5]).is_err());
+        assert!(Grid::new(usize::MAX, 2, Vec::<u8>::new()).is_err());
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let err = "abc\nde".parse::<Grid<char>>().unwrap_err();
+        let err = err.downcast::<ParseError>().unwrap();
+        assert_eq!(2, err.line);
+        assert_eq!("Row has width 2, expected 3", err.message);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        let four: String = grid.neighbours_4(Point::ORIGIN).map(|(_, c)| c).collect();
+        assert_eq!("bd", four);
+
+        let eight: String = grid
+            .neighbours_8(Point::new(1, 1))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!("bcfda", eight);
+    }
+
+    #[test]
+    fn test_step_off_edge() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(None, grid.step(Point::ORIGIN, Direction::Up));
+        assert_eq!(None, grid.step(Point::ORIGIN, Direction::Left));
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            grid.step(Point::ORIGIN, Direction::Right)
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        assert_eq!("cf", grid.column(2).collect::<String>());
+        assert_eq!("ae", grid.diagonal(Point::ORIGIN).collect::<String>());
+        assert_eq!(
+            "ce",
+            grid.anti_diagonal(Point::new(2, 0)).collect::<String>()
+        );
+        assert_eq!(
+            "",
+            grid.walk(Point::new(5, 5), Direction::Right)
+                .collect::<String>()
+        );
+    }
+}
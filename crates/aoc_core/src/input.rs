@@ -0,0 +1,194 @@
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Environment variable naming a directory of inputs, laid out like [`user_input_dir`].
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read a day's puzzle input from when it is given explicitly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut raw_input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut raw_input)
+                    .context("Failed to read input from stdin")?;
+                Ok(raw_input)
+            }
+            Self::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read input from {}", path.display())),
+        }
+    }
+
+    /// Open the input for reading gradually, instead of all at once like [`read`](Self::read).
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Self::Stdin => Ok(Box::new(std::io::stdin().lock())),
+            Self::File(path) => {
+                let file = File::open(path)
+                    .with_context(|| format!("Failed to open input {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+/// `-` means stdin, anything else is a path.
+impl<T: AsRef<OsStr>> From<T> for InputSource {
+    fn from(value: T) -> Self {
+        let value = value.as_ref();
+        if value == "-" {
+            Self::Stdin
+        } else {
+            Self::File(PathBuf::from(value))
+        }
+    }
+}
+
+/// None of the default input locations exist for a day.
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub day: u8,
+    /// Each location that was checked, with a description of where it came from.
+    pub tried: Vec<(String, PathBuf)>,
+}
+
+impl Display for InputNotFound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "No input found for day {:02}, tried:", self.day)?;
+        for (origin, path) in &self.tried {
+            write!(f, "\n  - {} ({})", path.display(), origin)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputNotFound {}
+
+/// Name of a day's input file inside an inputs directory.
+pub fn input_file_name(day: u8) -> String {
+    format!("day_{day:02}.txt")
+}
+
+/// Per-user directory holding one input file per day.
+///
+/// This is `$XDG_DATA_HOME/aoc/inputs`, falling back to `~/.local/share/aoc/inputs`.
+pub fn user_input_dir() -> Option<PathBuf> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| Path::new(&home).join(".local").join("share"))
+        })?;
+    Some(data_home.join("aoc").join("inputs"))
+}
+
+/// Every location checked for a day's input when no source is given, in priority order:
+/// [`INPUT_DIR_VAR`], then [`user_input_dir`], then the day's own `fallback` path.
+fn candidates(day: u8, fallback: &Path) -> Vec<(String, PathBuf)> {
+    let file_name = input_file_name(day);
+    let mut candidates = Vec::new();
+
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR) {
+        candidates.push((
+            format!("${INPUT_DIR_VAR}"),
+            Path::new(&dir).join(&file_name),
+        ));
+    }
+    if let Some(dir) = user_input_dir() {
+        candidates.push(("user inputs directory".to_string(), dir.join(&file_name)));
+    }
+    candidates.push(("crate directory".to_string(), fallback.to_path_buf()));
+
+    candidates
+}
+
+/// Find the first existing file among `candidates`.
+fn locate_in(day: u8, candidates: Vec<(String, PathBuf)>) -> Result<PathBuf, InputNotFound> {
+    candidates
+        .iter()
+        .find(|(_, path)| path.is_file())
+        .map(|(_, path)| path.clone())
+        .ok_or(InputNotFound {
+            day,
+            tried: candidates,
+        })
+}
+
+/// Find a day's input in the default locations, see [`candidates`] for the search order.
+pub fn locate(day: u8, fallback: &Path) -> Result<PathBuf, InputNotFound> {
+    locate_in(day, candidates(day, fallback))
+}
+
+/// Read a day's input from `source` if given, otherwise from the first default location that exists.
+pub fn load(day: u8, source: Option<&InputSource>, fallback: &Path) -> Result<String> {
+    match source {
+        Some(source) => source.read(),
+        None => InputSource::File(locate(day, fallback)?).read(),
+    }
+}
+
+/// Like [`load`], but opens the input for reading gradually.
+pub fn open(day: u8, source: Option<&InputSource>, fallback: &Path) -> Result<Box<dyn BufRead>> {
+    match source {
+        Some(source) => source.open(),
+        None => InputSource::File(locate(day, fallback)?).open(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("inputs/day_01.txt")),
+            InputSource::from("inputs/day_01.txt")
+        );
+    }
+
+    #[test]
+    fn test_locate_first_existing() {
+        let dir = std::env::temp_dir().join(format!("aoc_input_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let existing = dir.join("exists.txt");
+        std::fs::write(&existing, "1 2").unwrap();
+
+        let candidates = vec![
+            ("first".to_string(), dir.join("missing.txt")),
+            ("second".to_string(), existing.clone()),
+        ];
+        let actual = locate_in(1, candidates);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(existing, actual.unwrap());
+    }
+
+    #[test]
+    fn test_not_found_lists_tried() {
+        let candidates = vec![(
+            "crate directory".to_string(),
+            PathBuf::from("/nonexistent/input.txt"),
+        )];
+
+        let err = locate_in(7, candidates).unwrap_err();
+        assert_eq!(
+            "No input found for day 07, tried:\n  - /nonexistent/input.txt (crate directory)",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Shared building blocks for the daily puzzle solutions.
+
+pub mod answers;
+pub mod bench;
+pub mod counter;
+pub mod differential;
+mod error;
+pub mod explain;
+pub mod fetch;
+pub mod fixture;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+mod point;
+pub mod property;
+mod solution;
+pub mod stream;
+
+pub use crate::counter::Counter;
+pub use crate::error::{ErrorKind, StageError};
+pub use crate::grid::Grid;
+pub use crate::parse::ParseError;
+pub use crate::point::{Direction, Point};
+pub use crate::solution::{run, DynSolution, Part, PartOutcome, Solution};
@@ -0,0 +1,74 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// Apply `f` to each of `items`, keeping their order.
+///
+/// With the `parallel` feature the items are split into one contiguous chunk per available core,
+/// each mapped on its own thread. Without it they are mapped one after the other, so both builds
+/// give the same results.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if !cfg!(feature = "parallel") {
+        return items.iter().map(f).collect();
+    }
+
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    map_on(threads, items, f)
+}
+
+/// [`map`] with the items split over `threads` threads.
+fn map_on<T, R, F>(threads: usize, items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if threads <= 1 || items.len() < 2 {
+        return items.iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(results) => results,
+                // Let the caller see the original panic, as it would without threads
+                Err(payload) => std::panic::resume_unwind(payload),
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let nums: Vec<u64> = (0..1000).collect();
+        let squares: Vec<_> = nums.iter().map(|num| num * num).collect();
+
+        assert_eq!(squares, map(&nums, |num| num * num));
+        for threads in [1, 3, 8, 2000] {
+            assert_eq!(squares, map_on(threads, &nums, |num| num * num));
+        }
+        assert!(map_on(4, &[] as &[u64], |num| num * 2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Odd one out")]
+    fn test_map_propagates_panics() {
+        let nums: Vec<u64> = (0..100).collect();
+        map_on(4, &nums, |&num| assert!(num != 77, "Odd one out"));
+    }
+}
@@ -0,0 +1,153 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Range;
+use std::str::FromStr;
+
+/// An error in a specific part of one line of a puzzle input.
+///
+/// Displayed with the line and a caret underline below the offending text:
+///
+/// ```text
+/// line 3, column 5: invalid digit found in string
+///   |
+/// 3 | 2   x5
+///   |     ^^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting from 1.
+    pub line: usize,
+    /// Byte range of the offending text within the line. Empty when something is missing.
+    pub span: Range<usize>,
+    /// The whole line the error is on.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: &str, span: Range<usize>, message: impl Display) -> Self {
+        Self {
+            line,
+            span,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Column of the start of the span, counted in characters from 1.
+    pub fn column(&self) -> usize {
+        self.text
+            .get(..self.span.start)
+            .map_or(self.span.start, |before| before.chars().count())
+            + 1
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = self
+            .text
+            .get(self.span.clone())
+            .map_or(0, |spanned| spanned.chars().count())
+            .max(1);
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column(),
+            self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column() - 1),
+            "^".repeat(underline)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A numbered line of a puzzle input, for reporting [`ParseError`]s in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting from 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub const fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// Number the lines of `input` from 1.
+    pub fn numbered(input: &'a str) -> impl Iterator<Item = Self> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, text)| Self::new(idx + 1, text))
+    }
+
+    /// Error about `token`, which must be a slice of this line's text.
+    pub fn error_at(&self, token: &str, message: impl Display) -> ParseError {
+        let start = (token.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&start| start + token.len() <= self.text.len())
+            .expect("Token should be a slice of the line");
+        ParseError::new(self.number, self.text, start..start + token.len(), message)
+    }
+
+    /// Error about something missing from the end of the line.
+    pub fn error_at_end(&self, message: impl Display) -> ParseError {
+        let end = self.text.len();
+        ParseError::new(self.number, self.text, end..end, message)
+    }
+
+    /// Error about the line as a whole.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError::new(self.number, self.text, 0..self.text.len(), message)
+    }
+
+    /// Parse `token`, which must be a slice of this line's text, pointing at it on failure.
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token.parse().map_err(|e| self.error_at(token, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let line = Line::new(3, "2   x5");
+        let token = line.text.split_whitespace().nth(1).unwrap();
+
+        let e = line.parse::<u32>(token).unwrap_err();
+        assert_eq!(4..6, e.span);
+        assert_eq!(
+            "line 3, column 5: invalid digit found in string\n  |\n3 | 2   x5\n  |     ^^",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let e = Line::new(12, "190").error_at_end("Missing `:`");
+
+        assert_eq!(4, e.column());
+        assert_eq!(
+            "line 12, column 4: Missing `:`\n   |\n12 | 190\n   |    ^",
+            e.to_string()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a 2D plane, with `x` growing to the right and `y` growing downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Manhattan (taxicab) distance between two points.
+    pub const fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The point one step away in the given direction.
+    pub const fn step(self, direction: Direction) -> Self {
+        let offset = direction.offset();
+        Self::new(self.x + offset.x, self.y + offset.y)
+    }
+
+    /// Both coordinates as indices, or `None` if either is negative or too large.
+    pub fn to_indices(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Scale both coordinates.
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the eight compass directions on a grid, where up is towards negative `y`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    #[default]
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Offset of a single step in this direction.
+    pub const fn offset(&self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::UpRight => Point::new(1, -1),
+            Self::Right => Point::new(1, 0),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(0, 1),
+            Self::DownLeft => Point::new(-1, 1),
+            Self::Left => Point::new(-1, 0),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Turn clockwise by `eighths` of a full turn.
+    const fn turn(&self, eighths: usize) -> Self {
+        Self::ALL[(*self as usize + eighths) % 8]
+    }
+
+    /// Turn 90 degrees clockwise.
+    pub const fn rotate_right(&self) -> Self {
+        self.turn(2)
+    }
+
+    /// Turn 90 degrees counter-clockwise.
+    pub const fn rotate_left(&self) -> Self {
+        self.turn(6)
+    }
+
+    /// Turn 45 degrees clockwise.
+    pub const fn rotate_right_45(&self) -> Self {
+        self.turn(1)
+    }
+
+    /// Turn 45 degrees counter-clockwise.
+    pub const fn rotate_left_45(&self) -> Self {
+        self.turn(7)
+    }
+
+    pub const fn opposite(&self) -> Self {
+        self.turn(4)
+    }
+
+    /// Whether this is one of the four [`ORTHOGONAL`](Self::ORTHOGONAL) directions.
+    pub const fn is_orthogonal(&self) -> bool {
+        matches!(self, Self::Up | Self::Right | Self::Down | Self::Left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+
+        assert_eq!(Point::new(-2, 2), a + b);
+        assert_eq!(Point::new(4, -6), a - b);
+        assert_eq!(Point::new(3, -6), a * 3);
+        assert_eq!(Point::new(-1, 2), -a);
+        assert_eq!(10, a.manhattan(b));
+    }
+
+    #[test]
+    fn test_point_to_indices() {
+        assert_eq!(Some((1, 2)), Point::new(1, 2).to_indices());
+        assert_eq!(None, Point::new(0, -1).to_indices());
+    }
+
+    #[test]
+    fn test_direction_rotation() {
+        assert_eq!(Direction::Right, Direction::Up.rotate_right());
+        assert_eq!(Direction::Left, Direction::Up.rotate_left());
+        assert_eq!(Direction::UpLeft, Direction::Up.rotate_left_45());
+        assert_eq!(Direction::DownRight, Direction::Right.rotate_right_45());
+        assert_eq!(Direction::DownLeft, Direction::UpRight.opposite());
+    }
+
+    #[test]
+    fn test_direction_offsets_cancel() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                Point::ORIGIN,
+                Point::ORIGIN.step(direction).step(direction.opposite())
+            );
+        }
+    }
+}
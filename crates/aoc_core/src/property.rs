@@ -0,0 +1,38 @@
+use std::fmt::Debug;
+
+use crate::generate::Rng;
+
+/// Check that `property` holds for `cases` random values made by `generate`, each from its own
+/// seed.
+///
+/// Panics with the seed and the value on the first one it doesn't hold for, so the failure can be
+/// reproduced with [`Rng::new`].
+#[track_caller]
+pub fn check<T: Debug>(
+    cases: u64,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> bool,
+) {
+    for seed in 0..cases {
+        let value = generate(&mut Rng::new(seed));
+        if !property(&value) {
+            panic!("Property doesn't hold for seed {seed}: {value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_holds() {
+        check(100, |rng| rng.range(0..=10), |&num| num <= 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Property doesn't hold for seed")]
+    fn test_check_fails() {
+        check(100, |rng| rng.range(0..=10), |&num| num < 10);
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::answers::ANSWERS_FILE;
+use crate::bench::{self, Stage, Stats};
+use crate::differential::DiffFn;
+use crate::error::StageError;
+use crate::explain::ExplainFn;
+use crate::generate::GenerateFn;
+use crate::input::{self, InputSource};
+use crate::stream::StreamFn;
+
+/// One half of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+
+    pub const fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::One => "one",
+                Self::Two => "two",
+            }
+        )
+    }
+}
+
+/// The answer to one part of a day, and how long it took to solve.
+#[derive(Debug)]
+pub struct PartOutcome {
+    pub part: Part,
+    pub answer: Result<String>,
+    pub duration: Duration,
+}
+
+/// A solution for a single day, split into a parsing stage and one stage per part.
+///
+/// The input is parsed once and then shared by both parts, so tooling can time or verify each
+/// stage on its own.
+pub trait Solution {
+    /// Day of the calendar this solves, starting at 1.
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input.
+    type Input;
+
+    /// Location of the puzzle input bundled with the day's crate, used when no other input
+    /// source is found. See [`input::load`](crate::input::load).
+    fn input_path() -> PathBuf;
+
+    /// Location of the recorded answers for this day, next to the bundled input.
+    fn answers_path() -> PathBuf {
+        Self::input_path().with_file_name(ANSWERS_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> Result<impl Display>;
+
+    fn part_two(input: &Self::Input) -> Result<impl Display>;
+
+    /// How to solve this day reading the input one line at a time, if it can be.
+    ///
+    /// Days opt in by implementing [`StreamingSolution`](crate::stream::StreamingSolution) and
+    /// returning [`stream::solve`](crate::stream::solve) for themselves.
+    fn streaming() -> Option<StreamFn> {
+        None
+    }
+
+    /// How to generate random inputs for this day, if it can.
+    ///
+    /// Days opt in by implementing [`Generator`](crate::generate::Generator) and returning
+    /// [`generate::input`](crate::generate::input) for themselves.
+    fn generator() -> Option<GenerateFn> {
+        None
+    }
+
+    /// How to compare this day against a reference implementation on generated inputs, if it
+    /// has one.
+    ///
+    /// Days opt in by implementing [`Reference`](crate::differential::Reference) and returning
+    /// [`differential::check`](crate::differential::check) for themselves.
+    fn differential() -> Option<DiffFn> {
+        None
+    }
+
+    /// How to break this day's answers down into the terms they add up, if it can.
+    ///
+    /// Days opt in by implementing [`Explain`](crate::explain::Explain) and returning
+    /// [`explain::explain`](crate::explain::explain) for themselves.
+    fn explain() -> Option<ExplainFn> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can be stored and run
+/// together.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    fn input_path(&self) -> PathBuf;
+
+    fn answers_path(&self) -> PathBuf;
+
+    /// Parse the input once and solve each of the given parts against it.
+    ///
+    /// Returns an error if parsing fails; otherwise each part gets its own outcome.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<PartOutcome>>;
+
+    /// Time each stage of the solution, see [`bench::measure`].
+    fn bench(&self, input: &str, iterations: usize) -> Result<Vec<(Stage, Stats)>>;
+
+    /// See [`Solution::streaming`].
+    fn streaming(&self) -> Option<StreamFn>;
+
+    /// See [`Solution::generator`].
+    fn generator(&self) -> Option<GenerateFn>;
+
+    /// See [`Solution::differential`].
+    fn differential(&self) -> Option<DiffFn>;
+
+    /// See [`Solution::explain`].
+    fn explain(&self) -> Option<ExplainFn>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input_path(&self) -> PathBuf {
+        S::input_path()
+    }
+
+    fn answers_path(&self) -> PathBuf {
+        S::answers_path()
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<PartOutcome>> {
+        let input = S::parse(input).context(StageError::parse(S::DAY))?;
+
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part_one(&input).map(|ans| ans.to_string()),
+                    Part::Two => S::part_two(&input).map(|ans| ans.to_string()),
+                }
+                .context(StageError::solve(S::DAY, part));
+                PartOutcome {
+                    part,
+                    answer,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<Vec<(Stage, Stats)>> {
+        bench::measure::<S>(input, iterations)
+    }
+
+    fn streaming(&self) -> Option<StreamFn> {
+        S::streaming()
+    }
+
+    fn generator(&self) -> Option<GenerateFn> {
+        S::generator()
+    }
+
+    fn differential(&self) -> Option<DiffFn> {
+        S::differential()
+    }
+
+    fn explain(&self) -> Option<ExplainFn> {
+        S::explain()
+    }
+}
+
+/// Entry point shared by each day's binary: reads the input and prints both answers.
+///
+/// The first command line argument, if any, is the input source: a path, or `-` for stdin.
+/// Use [`ErrorKind::of`](crate::ErrorKind::of) on a returned error to pick the exit status.
+pub fn run(solution: &dyn DynSolution) -> Result<()> {
+    println!("===== DAY {:02} =====", solution.day());
+
+    let source = std::env::args_os().nth(1).map(InputSource::from);
+    let raw_input = input::load(solution.day(), source.as_ref(), &solution.input_path())?;
+
+    for outcome in solution.solve(&raw_input, &Part::ALL)? {
+        println!("Part {}:\t{}", outcome.part, outcome.answer?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::fmt::Display;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::parse::Line;
+use crate::{Part, PartOutcome, Solution, StageError};
+
+/// Solves parts of a day by reading its input line by line, see [`Solution::streaming`].
+pub type StreamFn = fn(&mut dyn BufRead, &[Part]) -> Result<Vec<PartOutcome>>;
+
+/// A [`Solution`] whose parts can also be solved one line of input at a time, so memory use
+/// stays bounded however large the input is.
+///
+/// Each line is parsed into an [`Item`](Self::Item) and fed to a running state per part, which
+/// is turned into the answer once the input runs out.
+pub trait StreamingSolution: Solution {
+    /// One parsed line of the input.
+    type Item;
+
+    /// Running state of one part while items are fed to it.
+    type State: Default;
+
+    fn parse_line(line: Line) -> Result<Self::Item>;
+
+    /// Update the running state of `part` with the next item.
+    fn feed(part: Part, state: &mut Self::State, item: &Self::Item) -> Result<()>;
+
+    /// Answer to `part` once every item has been fed.
+    fn finish(part: Part, state: Self::State) -> Result<impl Display>;
+}
+
+/// Solve `parts` reading `input` one line at a time.
+///
+/// Returns an error if reading or parsing any line fails. A part that fails stops being fed, and
+/// its outcome holds the error. Each part's duration covers only the time spent feeding and
+/// finishing it.
+pub fn solve<S: StreamingSolution>(
+    input: &mut dyn BufRead,
+    parts: &[Part],
+) -> Result<Vec<PartOutcome>> {
+    let mut states: Vec<_> = parts
+        .iter()
+        .map(|&part| (part, Ok(S::State::default()), Duration::ZERO))
+        .collect();
+
+    let mut text = String::new();
+    let mut number = 0;
+    loop {
+        text.clear();
+        if input.read_line(&mut text).context("Failed to read input")? == 0 {
+            break;
+        }
+        number += 1;
+
+        // Line endings are stripped the same way as `str::lines`
+        let trimmed = text.strip_suffix('\n').unwrap_or(&text);
+        let trimmed = trimmed.strip_suffix('\r').unwrap_or(trimmed);
+        let item = S::parse_line(Line::new(number, trimmed)).context(StageError::parse(S::DAY))?;
+
+        for (part, state, duration) in &mut states {
+            let Ok(running) = state else {
+                continue;
+            };
+            let start = Instant::now();
+            let fed = S::feed(*part, running, &item);
+            *duration += start.elapsed();
+            if let Err(e) = fed {
+                *state = Err(e);
+            }
+        }
+    }
+
+    Ok(states
+        .into_iter()
+        .map(|(part, state, mut duration)| {
+            let start = Instant::now();
+            let answer = state
+                .and_then(|state| S::finish(part, state).map(|ans| ans.to_string()))
+                .context(StageError::solve(S::DAY, part));
+            duration += start.elapsed();
+            PartOutcome {
+                part,
+                answer,
+                duration,
+            }
+        })
+        .collect())
+}
+
+/// Peak resident memory of this process so far, in bytes.
+///
+/// Only available on Linux, where it is read from `/proc/self/status`.
+pub fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    /// Sums the numbers on each line, and the largest number for part two.
+    struct Sums;
+
+    impl Solution for Sums {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u64>;
+
+        fn input_path() -> PathBuf {
+            PathBuf::new()
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Line::numbered(input)
+                .map(|line| Ok(line.parse(line.text)?))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Ok(input.iter().sum::<u64>())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<impl Display> {
+            input.iter().max().context("Empty input")
+        }
+    }
+
+    impl StreamingSolution for Sums {
+        type Item = u64;
+
+        type State = Option<u64>;
+
+        fn parse_line(line: Line) -> Result<Self::Item> {
+            Ok(line.parse(line.text)?)
+        }
+
+        fn feed(part: Part, state: &mut Self::State, item: &Self::Item) -> Result<()> {
+            let running = state.get_or_insert(0);
+            match part {
+                Part::One => *running += item,
+                Part::Two => *running = (*running).max(*item),
+            }
+            Ok(())
+        }
+
+        fn finish(_part: Part, state: Self::State) -> Result<impl Display> {
+            state.context("Empty input")
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let outcomes = solve::<Sums>(&mut "3\r\n10\n4".as_bytes(), &Part::ALL).unwrap();
+
+        let answers: Vec<_> = outcomes
+            .into_iter()
+            .map(|outcome| outcome.answer.unwrap())
+            .collect();
+        assert_eq!(vec!["17", "10"], answers);
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let e = solve::<Sums>(&mut "3\nx\n".as_bytes(), &[Part::One]).unwrap_err();
+
+        assert_eq!(2, e.downcast_ref::<crate::ParseError>().unwrap().line);
+    }
+}
@@ -0,0 +1,95 @@
+//! Times each way of sorting and counting location IDs over lists of different lengths and
+//! spreads of IDs, to find where the linear-time paths in `day_01::bounded` start paying off.
+//! The spread is the range of IDs as a multiple of the length of the list.
+//!
+//! Run with `cargo bench -p day_01`. The thresholds in `day_01::bounded` are picked from where
+//! the columns cross over: counting sort wins up to a spread of about 1, a table of counts beats
+//! a `Counter` up to a spread of about 16, and radix sort beats a comparison sort from about
+//! 1024 IDs, as long as it takes at most 3 passes. At 4 passes the two are about as quick, and
+//! past that the comparison sort wins.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use aoc_core::bench;
+use aoc_core::generate::Rng;
+use aoc_core::Counter;
+use day_01::bounded::{self, Bounds, Counts};
+use day_01::Num;
+
+/// Lengths of the lists timed.
+const LENGTHS: [usize; 6] = [64, 256, 1024, 8192, 65_536, 1 << 20];
+
+/// Spreads of IDs timed.
+const SPREADS: [usize; 6] = [1, 4, 16, 256, 4096, 65_536];
+
+/// About how many IDs to get through when timing each cell, spread over the runs.
+const WORK: usize = 1 << 22;
+
+fn main() -> Result<()> {
+    println!(
+        "{:>8} {:>8} {:>6} | {:>10} {:>10} {:>10} | {:>10} {:>10}",
+        "length", "spread", "passes", "sort", "counting", "radix", "Counter", "table"
+    );
+
+    for len in LENGTHS {
+        for spread in SPREADS {
+            let range = len.saturating_mul(spread).min(Num::MAX as usize);
+            let mut rng = Rng::new(len as u64 ^ spread as u64);
+            let mut list = || -> Vec<Num> {
+                (0..len)
+                    .map(|_| rng.range(0..=range as u64 - 1) as Num)
+                    .collect()
+            };
+            let (a, b) = (list(), list());
+            let bounds = Bounds::of(&b).expect("List shouldn't be empty");
+            let iterations = (WORK / len).max(3);
+
+            let sorted = |sort: &dyn Fn(&mut [Num])| {
+                median(iterations, || {
+                    let mut list = b.clone();
+                    sort(&mut list);
+                    Ok(list)
+                })
+            };
+            let sort = sorted(&|list| list.sort_unstable())?;
+            // A table much larger than the list would only measure allocating it
+            let counting = (spread <= 256)
+                .then(|| sorted(&|list| bounded::counting_sort(list, bounds)))
+                .transpose()?;
+            let radix = sorted(&|list| bounded::radix_sort(list, bounds))?;
+
+            let counter = median(iterations, || {
+                let counter: Counter<_> = b.iter().collect();
+                Ok(a.iter().map(|id| counter.count(&id)).sum::<usize>())
+            })?;
+            let table = (spread <= 256)
+                .then(|| {
+                    median(iterations, || {
+                        let counts = Counts::dense(&b, bounds);
+                        Ok(a.iter().map(|id| counts.count(id)).sum::<usize>())
+                    })
+                })
+                .transpose()?;
+
+            println!(
+                "{len:>8} {spread:>8} {:>6} | {:>10} {:>10} {:>10} | {:>10} {:>10}",
+                bounds.radix_passes(),
+                show(Some(sort)),
+                show(counting),
+                show(Some(radix)),
+                show(Some(counter)),
+                show(table)
+            );
+        }
+    }
+    Ok(())
+}
+
+fn median<T>(iterations: usize, f: impl FnMut() -> Result<T>) -> Result<Duration> {
+    Ok(bench::time(iterations, f)?.median)
+}
+
+fn show(duration: Option<Duration>) -> String {
+    duration.map_or("-".to_string(), |duration| format!("{duration:.2?}"))
+}
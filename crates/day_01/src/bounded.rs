@@ -0,0 +1,273 @@
+use aoc_core::Counter;
+
+use crate::number::Number;
+
+/// Lists of at least this many IDs are radix sorted when they aren't dense enough to counting
+/// sort. Below it a comparison sort is as quick, see `benches/bounded.rs`.
+pub const RADIX_MIN_LEN: usize = 1 << 10;
+
+/// Lists are radix sorted only when it takes at most this many passes over them. Past that, a
+/// comparison sort is as quick even for long lists, see `benches/bounded.rs`.
+pub const RADIX_MAX_PASSES: u32 = 3;
+
+/// Lists are counting sorted when the range of their IDs is at most this many times their
+/// length. Past that, filling in the gaps between IDs makes it slower than radix sort, see
+/// `benches/bounded.rs`.
+pub const COUNTING_SORT_SPREAD: usize = 1;
+
+/// IDs are counted in a table rather than a [`Counter`] when their range is at most this many
+/// times the length of the list. Past that, allocating the table makes it slower, see
+/// `benches/bounded.rs`.
+pub const TABLE_SPREAD: usize = 16;
+
+/// Bits of an ID sorted on in each pass of [`radix_sort`].
+const RADIX_BITS: u32 = 8;
+
+/// The smallest and largest ID of a list, if every ID fits in a `usize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Bounds {
+    /// Bounds of `list`, or `None` if it's empty or has an ID too large for a `usize`.
+    pub fn of<N: Number>(list: &[N]) -> Option<Self> {
+        let mut ids = list.iter().map(Number::to_index);
+        let first = ids.next()??;
+        ids.try_fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |bounds, id| {
+                let id = id?;
+                Some(Self {
+                    min: bounds.min.min(id),
+                    max: bounds.max.max(id),
+                })
+            },
+        )
+    }
+
+    /// Number of IDs from the smallest to the largest, inclusive.
+    pub fn range(&self) -> usize {
+        (self.max - self.min).saturating_add(1)
+    }
+
+    /// Bits needed for the distance of any ID from the smallest.
+    pub fn bits(&self) -> u32 {
+        usize::BITS - (self.max - self.min).leading_zeros()
+    }
+
+    /// Passes [`radix_sort`] makes over a list within these bounds.
+    pub fn radix_passes(&self) -> u32 {
+        self.bits().div_ceil(RADIX_BITS)
+    }
+
+    /// Whether the range of IDs is at most `spread` times `len`, the length of their list.
+    pub fn is_dense(&self, len: usize, spread: usize) -> bool {
+        self.range() <= len.saturating_mul(spread)
+    }
+}
+
+/// Sort `list`, picking the quickest way for its length and the range of its IDs.
+///
+/// Dense IDs are counting sorted and long lists of IDs within a narrow range are radix sorted,
+/// both in linear time. Other lists fall back to a comparison sort. See
+/// [`COUNTING_SORT_SPREAD`], [`RADIX_MIN_LEN`] and [`RADIX_MAX_PASSES`].
+pub fn sort<N: Number>(list: &mut [N]) {
+    match Bounds::of(list) {
+        Some(bounds) if bounds.is_dense(list.len(), COUNTING_SORT_SPREAD) => {
+            counting_sort(list, bounds)
+        }
+        Some(bounds)
+            if list.len() >= RADIX_MIN_LEN && bounds.radix_passes() <= RADIX_MAX_PASSES =>
+        {
+            radix_sort(list, bounds)
+        }
+        _ => list.sort_unstable(),
+    }
+}
+
+/// Sort `list` by counting how often each ID within `bounds` appears, in time linear in the
+/// length of the list plus the range of the bounds.
+pub fn counting_sort<N: Number>(list: &mut [N], bounds: Bounds) {
+    let mut counts = vec![0; bounds.range()];
+    for id in list.iter() {
+        counts[offset(id, bounds)] += 1;
+    }
+
+    let mut start = 0;
+    for (offset, &count) in counts.iter().enumerate().filter(|(_, &count)| count > 0) {
+        list[start..start + count].fill(from_index(bounds.min + offset));
+        start += count;
+    }
+}
+
+/// Sort `list` a few bits at a time, least significant first, in time linear in its length
+/// times the number of bits in the range of `bounds`.
+pub fn radix_sort<N: Number>(list: &mut [N], bounds: Bounds) {
+    let mut offsets: Vec<usize> = list.iter().map(|id| offset(id, bounds)).collect();
+    let mut sorted = vec![0; offsets.len()];
+    let mask = (1 << RADIX_BITS) - 1;
+
+    for shift in (0..bounds.bits()).step_by(RADIX_BITS as usize) {
+        let mut starts = [0; 1 << RADIX_BITS];
+        for offset in &offsets {
+            starts[(offset >> shift) & mask] += 1;
+        }
+        let mut start = 0;
+        for slot in &mut starts {
+            (*slot, start) = (start, start + *slot);
+        }
+
+        for &offset in &offsets {
+            let digit = (offset >> shift) & mask;
+            sorted[starts[digit]] = offset;
+            starts[digit] += 1;
+        }
+        std::mem::swap(&mut offsets, &mut sorted);
+    }
+
+    for (slot, offset) in list.iter_mut().zip(offsets) {
+        *slot = from_index(bounds.min + offset);
+    }
+}
+
+/// How many times each ID appears in a list.
+pub enum Counts<'a, N: Number> {
+    /// A table with a count for every ID within the bounds of a dense list.
+    Dense {
+        bounds: Bounds,
+        counts: Vec<usize>,
+    },
+    Sparse(Counter<&'a N>),
+}
+
+impl<'a, N: Number> Counts<'a, N> {
+    /// Count the IDs in `list`, in a table if they are dense enough, see [`TABLE_SPREAD`].
+    pub fn new(list: &'a [N]) -> Self {
+        match Bounds::of(list) {
+            Some(bounds) if bounds.is_dense(list.len(), TABLE_SPREAD) => Self::dense(list, bounds),
+            _ => Self::Sparse(list.iter().collect()),
+        }
+    }
+
+    /// Count the IDs in `list` in a table, which must be within `bounds`.
+    pub fn dense(list: &[N], bounds: Bounds) -> Self {
+        let mut counts = vec![0; bounds.range()];
+        for id in list {
+            counts[offset(id, bounds)] += 1;
+        }
+        Self::Dense { bounds, counts }
+    }
+
+    /// How many times `id` appears, zero if it doesn't.
+    pub fn count(&self, id: &N) -> usize {
+        match self {
+            Self::Dense { bounds, counts } => id
+                .to_index()
+                .and_then(|id| counts.get(id.checked_sub(bounds.min)?))
+                .copied()
+                .unwrap_or(0),
+            Self::Sparse(counter) => counter.count(&id),
+        }
+    }
+}
+
+/// Distance of `id` from the smallest ID within `bounds`, which it must be within.
+fn offset<N: Number>(id: &N, bounds: Bounds) -> usize {
+    id.to_index().expect("ID should be within the bounds") - bounds.min
+}
+
+/// The ID at `index`, which must have come from [`Number::to_index`].
+fn from_index<N: Number>(index: usize) -> N {
+    match N::try_from(index) {
+        Ok(id) => id,
+        Err(_) => unreachable!("Index {index} should have come from an ID"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::generate::Rng;
+    use aoc_core::property;
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Some(Bounds { min: 3, max: 9 }), Bounds::of(&[5u32, 9, 3]));
+        assert_eq!(None, Bounds::of::<u32>(&[]));
+        assert_eq!(None, Bounds::of(&[1, u128::MAX]));
+        assert!(Bounds { min: 3, max: 9 }.is_dense(2, 4));
+        assert!(!Bounds { min: 3, max: 9 }.is_dense(2, 1));
+        assert_eq!(
+            usize::MAX,
+            Bounds {
+                min: 0,
+                max: usize::MAX
+            }
+            .range()
+        );
+        assert_eq!(3, Bounds { min: 3, max: 9 }.bits());
+        assert_eq!(0, Bounds { min: 3, max: 3 }.bits());
+        assert_eq!(
+            3,
+            Bounds {
+                min: 0,
+                max: 1 << 16
+            }
+            .radix_passes()
+        );
+    }
+
+    #[test]
+    fn test_sorts_agree() {
+        let list = |rng: &mut Rng| -> Vec<u32> {
+            let len = rng.range(1..=2000) as usize;
+            let spread = *rng.choose(&[10, 1000, u64::from(u32::MAX)]);
+            let min = rng.range(0..=u64::from(u32::MAX) - spread);
+            (0..len)
+                .map(|_| (min + rng.range(0..=spread)) as u32)
+                .collect()
+        };
+
+        property::check(200, list, |list| {
+            let mut expected = list.clone();
+            expected.sort_unstable();
+            let bounds = Bounds::of(list).unwrap();
+
+            let sorts_with = |sort: &dyn Fn(&mut [u32])| {
+                let mut sorted = list.clone();
+                sort(&mut sorted);
+                sorted == expected
+            };
+
+            sorts_with(&sort)
+                && sorts_with(&|list| radix_sort(list, bounds))
+                // Counting sort needs a table as large as the range
+                && (bounds.range() > 1 << 16 || sorts_with(&|list| counting_sort(list, bounds)))
+        });
+    }
+
+    #[test]
+    fn test_counts() {
+        let list = [7u32, 3, 7, 5, 7];
+        let bounds = Bounds::of(&list).unwrap();
+
+        for counts in [
+            Counts::dense(&list, bounds),
+            Counts::Sparse(list.iter().collect()),
+        ] {
+            assert_eq!(3, counts.count(&7));
+            assert_eq!(1, counts.count(&3));
+            assert_eq!(0, counts.count(&4));
+            assert_eq!(0, counts.count(&1));
+            assert_eq!(0, counts.count(&100));
+        }
+        assert!(matches!(Counts::new(&list), Counts::Dense { .. }));
+        assert!(matches!(Counts::new(&[1u32, 1000]), Counts::Sparse(_)));
+    }
+}
@@ -0,0 +1,195 @@
+use anyhow::{bail, Result};
+use aoc_core::parse::Line;
+use aoc_core::Grid;
+
+use crate::number::Number;
+
+/// Parse each line into a row of numbers, returning the columns.
+///
+/// The first line sets how many columns there are, and every other line must have as many
+/// numbers.
+pub fn parse_columns<N: Number, T: AsRef<str>>(
+    input: impl IntoIterator<Item = T>,
+) -> Result<Vec<Vec<N>>> {
+    let mut columns: Vec<Vec<N>> = Vec::new();
+
+    for (idx, text) in input.into_iter().enumerate() {
+        let line = Line::new(idx + 1, text.as_ref());
+        let row = line
+            .text
+            .split_whitespace()
+            .map(|token| line.parse(token))
+            .collect::<Result<Vec<N>, _>>()?;
+
+        if idx == 0 {
+            if row.is_empty() {
+                return Err(line.error_at_end("Input missing first number").into());
+            }
+            columns = vec![Vec::new(); row.len()];
+        } else if row.len() != columns.len() {
+            return Err(line
+                .error(format!(
+                    "Expected {} numbers, found {}",
+                    columns.len(),
+                    row.len()
+                ))
+                .into());
+        }
+
+        for (column, num) in columns.iter_mut().zip(row) {
+            column.push(num);
+        }
+    }
+
+    Ok(columns)
+}
+
+/// A way of measuring how far apart two lists are, once both are sorted.
+pub trait Metric {
+    /// Distance between two numbers paired up by sorting.
+    fn pair<N: Number>(&self, a: &N, b: &N) -> f64;
+
+    /// Total distance between two sorted columns of the same length.
+    fn columns<N: Number>(&self, a: &[N], b: &[N]) -> f64 {
+        a.iter().zip(b).map(|(a, b)| self.pair(a, b)).sum()
+    }
+}
+
+/// Absolute difference, as in part one.
+pub struct Absolute;
+
+impl Metric for Absolute {
+    fn pair<N: Number>(&self, a: &N, b: &N) -> f64 {
+        a.abs_diff(b).to_f64()
+    }
+}
+
+/// Squared difference, so a few large gaps outweigh many small ones.
+pub struct Squared;
+
+impl Metric for Squared {
+    fn pair<N: Number>(&self, a: &N, b: &N) -> f64 {
+        a.abs_diff(b).to_f64().powi(2)
+    }
+}
+
+/// Absolute difference relative to the larger number, between 0 and 1 for each pair.
+pub struct Relative;
+
+impl Metric for Relative {
+    fn pair<N: Number>(&self, a: &N, b: &N) -> f64 {
+        let larger = a.max(b);
+        if *larger == N::default() {
+            0.0
+        } else {
+            a.abs_diff(b).to_f64() / larger.to_f64()
+        }
+    }
+}
+
+/// Absolute difference between the ranks of the numbers among both columns together, so only
+/// their order matters and not how far apart they are.
+///
+/// Equal numbers share the lowest of their ranks.
+pub struct Rank;
+
+impl Metric for Rank {
+    // Two numbers on their own only have ranks 0 and 1 if they differ
+    fn pair<N: Number>(&self, a: &N, b: &N) -> f64 {
+        f64::from(u8::from(a != b))
+    }
+
+    fn columns<N: Number>(&self, a: &[N], b: &[N]) -> f64 {
+        let mut merged = [a, b].concat();
+        merged.sort_unstable();
+        let rank = |num: &N| merged.partition_point(|other| other < num);
+
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| rank(a).abs_diff(rank(b)) as f64)
+            .sum()
+    }
+}
+
+/// Distance between every pair of `columns` under `metric`, pairing up their numbers in sorted
+/// order.
+///
+/// Cell `(i, j)` holds the distance between columns `i` and `j`, so the matrix is symmetric with
+/// zeroes along the diagonal. Returns an error if the columns have different lengths.
+pub fn distance_matrix<N: Number>(columns: &[Vec<N>], metric: &impl Metric) -> Result<Grid<f64>> {
+    if let Some(column) = columns
+        .iter()
+        .find(|column| column.len() != columns[0].len())
+    {
+        bail!(
+            "Columns should have the same length, found {} and {}",
+            columns[0].len(),
+            column.len()
+        );
+    }
+
+    let sorted: Vec<Vec<N>> = columns
+        .iter()
+        .map(|column| {
+            let mut column = column.clone();
+            column.sort_unstable();
+            column
+        })
+        .collect();
+
+    let size = sorted.len();
+    let mut cells = vec![0.0; size * size];
+    for i in 0..size {
+        for j in i + 1..size {
+            let distance = metric.columns(&sorted[i], &sorted[j]);
+            cells[i * size + j] = distance;
+            cells[j * size + i] = distance;
+        }
+    }
+    Grid::new(size, size, cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4   1\n4   3   2\n2   5   3\n1   3   4\n3   9   5\n3   3   6\n";
+
+    #[test]
+    fn test_parse_columns() {
+        let columns = parse_columns::<u32, _>(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(3, columns.len());
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], columns[2]);
+        assert!(parse_columns::<u32, _>(["1 2 3", "4 5"]).is_err());
+        assert!(parse_columns::<u32, _>([""]).is_err());
+        assert!(parse_columns::<u32, _>([] as [&str; 0]).unwrap().is_empty());
+        assert!(parse_columns::<u16, _>(["1 65536"]).is_err());
+    }
+
+    #[test]
+    fn test_absolute_matches_part_one() {
+        let columns = parse_columns::<u64, _>(EXAMPLE.lines()).unwrap();
+        let matrix = distance_matrix(&columns, &Absolute).unwrap();
+
+        assert_eq!(Some(&[0.0, 11.0, 5.0][..]), matrix.row(0));
+        assert_eq!(Some(&[11.0, 0.0, 6.0][..]), matrix.row(1));
+        assert_eq!(Some(&[5.0, 6.0, 0.0][..]), matrix.row(2));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let (a, b) = (vec![1u32, 2, 10], vec![2, 4, 6]);
+
+        assert_eq!(1.0 + 4.0 + 16.0, Squared.columns(&a, &b));
+        assert_eq!(0.5 + 0.5 + 0.4, Relative.columns(&a, &b));
+        assert_eq!(0.0, Relative.pair(&0u32, &0));
+        // Ranks among 1, 2, 2, 4, 6, 10 are 0, 1, 1, 3, 4, 5
+        assert_eq!(1.0 + 2.0 + 1.0, Rank.columns(&a, &b));
+    }
+
+    #[test]
+    fn test_columns_of_different_lengths() {
+        assert!(distance_matrix(&[vec![1u32, 2], vec![3]], &Absolute).is_err());
+    }
+}
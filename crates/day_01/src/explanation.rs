@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use aoc_core::explain::{Explain, Explanation};
+use aoc_core::Part;
+
+use crate::bounded::{self, Counts};
+use crate::number::Number;
+use crate::Day01;
+
+/// Two numbers paired up by sorting both lists, and how far apart they are.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pair<N> {
+    pub left: N,
+    pub right: N,
+    pub distance: N,
+}
+
+/// A number of the left list, how many times it appears in the right list, and what that adds
+/// to the similarity score.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Similarity<N> {
+    pub id: N,
+    pub count: usize,
+    pub score: N,
+}
+
+/// The pairs of numbers in sorted order, whose distances add up to part one's answer.
+pub fn pairs<N: Number>(a: &[N], b: &[N]) -> Vec<Pair<N>> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    bounded::sort(&mut a);
+    bounded::sort(&mut b);
+
+    a.into_iter()
+        .zip(b)
+        .map(|(left, right)| Pair {
+            distance: left.abs_diff(&right),
+            left,
+            right,
+        })
+        .collect()
+}
+
+/// The similarity of each number of `a` in order, which add up to part two's answer.
+///
+/// Returns an error if any number's similarity doesn't fit in an `N`.
+pub fn similarities<N: Number>(a: &[N], b: &[N]) -> Result<Vec<Similarity<N>>> {
+    let counts = Counts::new(b);
+
+    a.iter()
+        .map(|id| {
+            let count = counts.count(id);
+            let score = N::try_from(count)
+                .ok()
+                .and_then(|count| id.checked_mul(&count))
+                .with_context(|| format!("Similarity of {id} overflows"))?;
+            Ok(Similarity {
+                id: id.clone(),
+                count,
+                score,
+            })
+        })
+        .collect()
+}
+
+/// Part one lists each pair by its position in sorted order, and part two lists each number of
+/// the left list by its line.
+impl Explain for Day01 {
+    fn explanation(part: Part, (a, b): &Self::Input) -> Result<Explanation> {
+        Ok(match part {
+            Part::One => Explanation::new(
+                ["Pair", "Left", "Right", "Distance"],
+                pairs(a, b).into_iter().enumerate().map(|(idx, pair)| {
+                    let row = vec![
+                        (idx + 1).to_string(),
+                        pair.left.to_string(),
+                        pair.right.to_string(),
+                        pair.distance.to_string(),
+                    ];
+                    (row, pair.distance)
+                }),
+            ),
+            Part::Two => Explanation::new(
+                ["Line", "Left", "Count", "Similarity"],
+                similarities(a, b)?
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, similarity)| {
+                        let row = vec![
+                            (idx + 1).to_string(),
+                            similarity.id.to_string(),
+                            similarity.count.to_string(),
+                            similarity.score.to_string(),
+                        ];
+                        (row, similarity.score)
+                    }),
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::explain;
+    use aoc_core::generate::{Generator, Rng};
+    use aoc_core::{property, Solution};
+
+    use crate::{part_one, part_two, Num};
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_explain_part_one() {
+        let explanation = explain::explain::<Day01>(EXAMPLE, Part::One).unwrap();
+
+        assert_eq!(6, explanation.rows.len());
+        assert_eq!(vec!["1", "1", "3", "2"], explanation.rows[0]);
+        assert_eq!(
+            vec![["6", "4", "9", "5"], ["1", "1", "3", "2"]],
+            explanation.top(2).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_explain_part_two() {
+        let explanation = explain::explain::<Day01>(EXAMPLE, Part::Two).unwrap();
+
+        assert_eq!(vec!["2", "4", "1", "4"], explanation.rows[1]);
+        assert_eq!(vec!["3", "2", "0", "0"], explanation.rows[2]);
+        assert_eq!(vec!["1", "3", "3", "9"], explanation.top(1).next().unwrap());
+    }
+
+    #[test]
+    fn test_terms_add_up_to_answers() {
+        let lists = |rng: &mut Rng| {
+            let raw_input = Day01::generate(rng.range(0..=40) as usize, rng);
+            Day01::parse(&raw_input).unwrap()
+        };
+
+        property::check(100, lists, |(a, b): &(Vec<Num>, Vec<Num>)| {
+            let distance: Num = pairs(a, b).iter().map(|pair| pair.distance).sum();
+            let score: Num = similarities(a, b)
+                .unwrap()
+                .iter()
+                .map(|similarity| similarity.score)
+                .sum();
+
+            distance == part_one(&mut a.clone(), &mut b.clone()).unwrap()
+                && score == part_two(a, b).unwrap()
+        });
+    }
+}
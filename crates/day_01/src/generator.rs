@@ -0,0 +1,22 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::Day01;
+
+impl Generator for Day01 {
+    /// `size` pairs of five digit location IDs. About a quarter of the right list repeats IDs
+    /// from the left, so they have some similarity.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let left: Vec<u64> = (0..size).map(|_| rng.range(10_000..=99_999)).collect();
+
+        left.iter()
+            .map(|a| {
+                let b = if rng.chance(0.25) {
+                    *rng.choose(&left)
+                } else {
+                    rng.range(10_000..=99_999)
+                };
+                format!("{a}   {b}\n")
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,203 @@
+pub mod bounded;
+pub mod compare;
+pub mod explanation;
+mod generator;
+pub mod number;
+mod reference;
+
+use crate::bounded::Counts;
+use crate::number::Number;
+
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::explain::{self, ExplainFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parse::Line;
+use aoc_core::Solution;
+
+/// Type for numbers used for the puzzle input and answers. Wider than the IDs need, so that
+/// answers adding up many IDs that fit in a `u32` don't overflow. The functions solving each
+/// part work with any [`Number`], for inputs that need wider ones.
+pub type Num = u64;
+
+// Path to the input data
+const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+/// Day 1: Historian Hysteria
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<Num>, Vec<Num>);
+
+    fn input_path() -> PathBuf {
+        PathBuf::from(INPUT_PATH)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.lines())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        let (mut a, mut b) = input.clone();
+        part_one(&mut a, &mut b)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        part_two(&input.0, &input.1)
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+
+    fn explain() -> Option<ExplainFn> {
+        Some(explain::explain::<Self>)
+    }
+}
+
+/// Parse each line into a pair of numbers, returning the left and right columns.
+pub fn parse_input<N: Number, T: AsRef<str>>(
+    input: impl IntoIterator<Item = T>,
+) -> Result<(Vec<N>, Vec<N>)> {
+    input
+        .into_iter()
+        .enumerate()
+        .map(|(idx, text)| {
+            let line = Line::new(idx + 1, text.as_ref());
+            let mut split = line.text.split_whitespace();
+
+            let a: N = line.parse(
+                split
+                    .next()
+                    .ok_or_else(|| line.error_at_end("Input missing first number"))?,
+            )?;
+
+            let b: N = line.parse(
+                split
+                    .next()
+                    .ok_or_else(|| line.error_at_end("Input missing second number"))?,
+            )?;
+
+            Ok((a, b))
+        })
+        .collect()
+}
+
+/// Total distance between the two lists, pairing up their numbers in sorted order.
+///
+/// Sorts both lists in place, in linear time if their IDs are bounded, see [`bounded::sort`].
+/// Returns an error if the total doesn't fit in an `N`.
+pub fn part_one<N: Number>(a: &mut [N], b: &mut [N]) -> Result<N> {
+    bounded::sort(a);
+    bounded::sort(b);
+    a.iter()
+        .zip(b)
+        .try_fold(N::default(), |total, (first, second)| {
+            total.checked_add(&first.abs_diff(second))
+        })
+        .context("Total distance overflows")
+}
+
+/// Similarity score: each number in `a` multiplied by how often it appears in `b`.
+///
+/// Counts `b` in a table if its IDs are dense, see [`Counts`]. Returns an error if the score
+/// doesn't fit in an `N`.
+pub fn part_two<N: Number>(a: &[N], b: &[N]) -> Result<N> {
+    let counts = Counts::new(b);
+
+    a.iter()
+        .try_fold(N::default(), |score, num| {
+            let count = N::try_from(counts.count(num)).ok()?;
+            score.checked_add(&num.checked_mul(&count)?)
+        })
+        .context("Similarity score overflows")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::generate::Rng;
+    use aoc_core::property;
+
+    aoc_core::fixture_tests!(crate::Day01);
+    aoc_core::generator_tests!(crate::Day01, size: 100, reference_size: 20);
+
+    #[test]
+    fn test_part_one_ignores_order() {
+        let lists = |rng: &mut Rng| {
+            let len = rng.range(0..=50) as usize;
+            let mut list =
+                || -> Vec<Num> { (0..len).map(|_| rng.range(0..=1000) as Num).collect() };
+            (list(), list())
+        };
+
+        property::check(200, lists, |(a, b)| {
+            let (mut shuffled_a, mut shuffled_b) = (a.clone(), b.clone());
+            let mut rng = Rng::new(a.len() as u64);
+            rng.shuffle(&mut shuffled_a);
+            rng.shuffle(&mut shuffled_b);
+
+            part_one(&mut a.clone(), &mut b.clone()).unwrap()
+                == part_one(&mut shuffled_a, &mut shuffled_b).unwrap()
+        });
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let big = [Num::MAX, Num::MAX];
+
+        assert!(part_one(&mut [0, 0], &mut big.clone()).is_err());
+        assert!(part_two(&big, &big).is_err());
+    }
+
+    #[test]
+    fn test_wider_numbers() {
+        let input = ["4294967295   4294967295", "4294967295   1"];
+
+        let (a, b) = parse_input::<u32, _>(input).unwrap();
+        assert!(part_two(&a, &b).is_err());
+
+        let (mut a, mut b) = parse_input::<u64, _>(input).unwrap();
+        assert_eq!(2 * 4294967295, part_two(&a, &b).unwrap());
+        assert_eq!(4294967294, part_one(&mut a, &mut b).unwrap());
+    }
+
+    #[test]
+    fn test_answers_wider_than_ids() {
+        let input = Day01::parse("4294967295   4294967295\n4294967295   4294967295\n").unwrap();
+
+        assert_eq!(
+            (4 * 4294967295u64).to_string(),
+            <Day01 as Solution>::part_two(&input).unwrap().to_string()
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_big_numbers() {
+        use num_bigint::BigUint;
+
+        // 2^128 doesn't fit even in a u128
+        let input = [
+            "340282366920938463463374607431768211456   1",
+            "1   340282366920938463463374607431768211456",
+        ];
+        assert!(parse_input::<u128, _>(input).is_err());
+
+        let (a, b) = parse_input::<BigUint, _>(input).unwrap();
+        assert_eq!(
+            "340282366920938463463374607431768211457",
+            part_two(&a, &b).unwrap().to_string()
+        );
+    }
+}
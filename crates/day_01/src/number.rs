@@ -0,0 +1,91 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A type day 1 can use for location IDs and the answers built from them.
+///
+/// Sums and products are checked, so answers that don't fit are reported as errors instead of
+/// wrapping around. [`Default`] must be zero.
+pub trait Number:
+    Clone
+    + Debug
+    + Default
+    + Display
+    + FromStr<Err: Display>
+    + Hash
+    + Ord
+    + TryFrom<usize>
+    + Send
+    + Sync
+    + 'static
+{
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    fn abs_diff(&self, other: &Self) -> Self;
+
+    /// This number as an index into a table, if it fits in one.
+    fn to_index(&self) -> Option<usize>;
+
+    /// The nearest `f64` to this number, for measures that aren't whole numbers.
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_number {
+    ($($num:ty),*) => {
+        $(
+            impl Number for $num {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$num>::checked_add(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$num>::checked_mul(*self, *other)
+                }
+
+                fn abs_diff(&self, other: &Self) -> Self {
+                    <$num>::abs_diff(*self, *other)
+                }
+
+                fn to_index(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u16, u32, u64, u128, usize);
+
+/// Never overflows, at the cost of allocating for every number.
+#[cfg(feature = "bigint")]
+impl Number for num_bigint::BigUint {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn abs_diff(&self, other: &Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn to_index(&self) -> Option<usize> {
+        usize::try_from(self).ok()
+    }
+
+    fn to_f64(&self) -> f64 {
+        num_traits::ToPrimitive::to_f64(self).unwrap_or(f64::INFINITY)
+    }
+}
@@ -0,0 +1,41 @@
+use anyhow::{Context, Result};
+use aoc_core::differential::Reference;
+use aoc_core::{Part, Solution};
+
+use crate::{Day01, Num};
+
+impl Reference for Day01 {
+    /// Pairs the lists up by repeatedly taking the smallest number left in each, and counts
+    /// appearances by scanning the whole right list for every number.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let (mut left, mut right) = Self::parse(raw_input)?;
+
+        let answer: Num = match part {
+            Part::One => {
+                let mut total: Num = 0;
+                while let Some(a) = take_smallest(&mut left) {
+                    let b = take_smallest(&mut right).context("Lists have different lengths")?;
+                    total = total.checked_add(a.abs_diff(b)).context("Overflow")?;
+                }
+                total
+            }
+            Part::Two => {
+                let mut score: Num = 0;
+                for a in &left {
+                    let count = right.iter().filter(|&b| b == a).count() as Num;
+                    score = a
+                        .checked_mul(count)
+                        .and_then(|product| score.checked_add(product))
+                        .context("Overflow")?;
+                }
+                score
+            }
+        };
+        Ok(answer.to_string())
+    }
+}
+
+fn take_smallest(list: &mut Vec<Num>) -> Option<Num> {
+    let idx = (0..list.len()).min_by_key(|&idx| list[idx])?;
+    Some(list.swap_remove(idx))
+}
@@ -0,0 +1,35 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::Day02;
+
+impl Generator for Day02 {
+    /// `size` reports of 5 to 8 levels. Most change steadily in one direction, and some of those
+    /// have one level replaced by a random one.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let len = rng.range(5..=8) as usize;
+                let increasing = rng.chance(0.5);
+                // Eight steps of at most 3 can't leave 1..=99 from here
+                let mut level = rng.range(25..=75);
+                let mut levels = Vec::with_capacity(len);
+                for _ in 0..len {
+                    levels.push(level);
+                    let step = rng.range(1..=3);
+                    level = if increasing {
+                        level + step
+                    } else {
+                        level - step
+                    };
+                }
+                if rng.chance(0.4) {
+                    let idx = rng.index(len);
+                    levels[idx] = rng.range(1..=99);
+                }
+
+                let levels: Vec<_> = levels.iter().map(u64::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect()
+    }
+}
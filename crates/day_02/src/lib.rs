@@ -0,0 +1,179 @@
+mod generator;
+mod reference;
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parallel;
+use aoc_core::parse::Line;
+use aoc_core::stream::{self, StreamFn, StreamingSolution};
+use aoc_core::{ParseError, Part, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+const DELTA: std::ops::RangeInclusive<i32> = 1..=3;
+
+/// Day 2: Red-Nosed Reports
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.lines())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_two(input))
+    }
+
+    fn streaming() -> Option<StreamFn> {
+        Some(stream::solve::<Self>)
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// Every report is judged on its own, so each part just counts the safe ones seen so far.
+impl StreamingSolution for Day02 {
+    type Item = Vec<u8>;
+
+    type State = usize;
+
+    fn parse_line(line: Line) -> Result<Self::Item> {
+        Ok(parse_report(line)?)
+    }
+
+    fn feed(part: Part, state: &mut Self::State, item: &Self::Item) -> Result<()> {
+        let safe = match part {
+            Part::One => evaluate_report_safety(item),
+            Part::Two => evaluate_damped_report_safety(item),
+        };
+        *state += usize::from(safe);
+        Ok(())
+    }
+
+    fn finish(_part: Part, state: Self::State) -> Result<impl Display> {
+        Ok(state)
+    }
+}
+
+/// Parse each line into a report of levels.
+pub fn parse_input<T: AsRef<str>>(raw_input: impl IntoIterator<Item = T>) -> Result<Vec<Vec<u8>>> {
+    raw_input
+        .into_iter()
+        .enumerate()
+        .map(|(idx, text)| Ok(parse_report(Line::new(idx + 1, text.as_ref()))?))
+        .collect()
+}
+
+/// Parse one line into a report of levels.
+pub fn parse_report(line: Line) -> Result<Vec<u8>, ParseError> {
+    line.text
+        .split_whitespace()
+        .map(|level| line.parse(level))
+        .collect()
+}
+
+/// Number of safe reports.
+pub fn part_one<T: AsRef<[u8]> + Sync>(input: &[T]) -> usize {
+    parallel::map(input, |report| evaluate_report_safety(report.as_ref()))
+        .into_iter()
+        .filter(|&safe| safe)
+        .count()
+}
+
+/// Number of reports that are safe, or become safe by removing a single level.
+pub fn part_two<T: AsRef<[u8]> + Sync>(input: &[T]) -> usize {
+    parallel::map(input, |report| {
+        evaluate_damped_report_safety(report.as_ref())
+    })
+    .into_iter()
+    .filter(|&safe| safe)
+    .count()
+}
+
+/// Evaluates whether a given row is "safe", either as is or once a single level is removed.
+pub fn evaluate_damped_report_safety(report: &[u8]) -> bool {
+    evaluate_report_safety(report)
+        || (0..report.len()).any(|i| {
+            let row = [&report[0..i], &report[i + 1..report.len()]].concat();
+            evaluate_report_safety(&row)
+        })
+}
+
+/// Evaluates whether a given row is considered "safe" or not.
+pub fn evaluate_report_safety(report: &[u8]) -> bool {
+    let mut safe = true;
+    let mut dir: Option<bool> = None;
+    for window in report.windows(2) {
+        let diff = i32::from(window[0]) - i32::from(window[1]);
+
+        match dir {
+            Some(d) => {
+                if diff.is_positive() != d {
+                    safe = false;
+                    break;
+                }
+            }
+            None => dir = Some(diff.is_positive()),
+        }
+
+        if !DELTA.contains(&diff.abs()) {
+            safe = false;
+            break;
+        }
+    }
+    safe
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::generate::Rng;
+    use aoc_core::property;
+
+    aoc_core::fixture_tests!(crate::Day02);
+    aoc_core::generator_tests!(crate::Day02, size: 100, reference_size: 20);
+
+    #[test]
+    fn test_safety_ignores_reversal() {
+        let report = |rng: &mut Rng| -> Vec<u8> {
+            let len = rng.range(0..=8) as usize;
+            let mut level = rng.range(1..=50) as u8;
+            // Mostly small steps in either direction, so plenty of reports are safe
+            (0..len)
+                .map(|_| {
+                    level = (i32::from(level) + rng.range(0..=8) as i32 - 4).clamp(1, 99) as u8;
+                    level
+                })
+                .collect()
+        };
+
+        property::check(500, report, |report| {
+            let reversed: Vec<_> = report.iter().rev().copied().collect();
+            evaluate_report_safety(report) == evaluate_report_safety(&reversed)
+                && evaluate_damped_report_safety(report) == evaluate_damped_report_safety(&reversed)
+        });
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use aoc_core::differential::Reference;
+use aoc_core::{Part, Solution};
+
+use crate::Day02;
+
+impl Reference for Day02 {
+    /// Checks the differences between neighbouring levels all at once, and dampens reports by
+    /// trying every level to remove.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let reports = Self::parse(raw_input)?;
+
+        let safe = reports.iter().filter(|report| match part {
+            Part::One => is_safe(report),
+            Part::Two => {
+                is_safe(report)
+                    || (0..report.len()).any(|idx| {
+                        let mut dampened = report.to_vec();
+                        dampened.remove(idx);
+                        is_safe(&dampened)
+                    })
+            }
+        });
+        Ok(safe.count().to_string())
+    }
+}
+
+fn is_safe(report: &[u8]) -> bool {
+    let diffs: Vec<i32> = report
+        .windows(2)
+        .map(|pair| i32::from(pair[1]) - i32::from(pair[0]))
+        .collect();
+    diffs.iter().all(|diff| (1..=3).contains(diff))
+        || diffs.iter().all(|diff| (-3..=-1).contains(diff))
+}
@@ -0,0 +1,45 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::Day03;
+
+/// Instructions that are almost, but not quite, a valid `mul`.
+const NEAR_MISSES: &[&str] = &[
+    "mul(1234,5)",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "mul(4*",
+    "mul(6,9!",
+    "?(12,34)",
+    "mul(,5)",
+    "do_not()",
+    "don't",
+];
+
+/// Characters scattered between the instructions.
+const NOISE: &[u8] = b"!@#$%^&*()[]{}<>,;:'? +-_/mulwhyselectfromwhat0123456789";
+
+impl Generator for Day03 {
+    /// `size` lines of corrupted memory, each a mix of valid `mul`, `do()` and `don't()`
+    /// instructions, near misses and noise.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut output = String::new();
+        for _ in 0..size {
+            for _ in 0..rng.range(20..=60) {
+                match rng.range(0..=9) {
+                    0..=3 => {
+                        let (lhs, rhs) = (rng.range(1..=999), rng.range(1..=999));
+                        output.push_str(&format!("mul({lhs},{rhs})"));
+                    }
+                    4 => output.push_str("do()"),
+                    5 => output.push_str("don't()"),
+                    6 => output.push_str(rng.choose::<&str>(NEAR_MISSES)),
+                    _ => {
+                        (0..rng.range(1..=8)).for_each(|_| output.push(*rng.choose(NOISE) as char))
+                    }
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
@@ -2,7 +2,8 @@ use std::iter::IntoIterator;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
-use anyhow::{Context, Error};
+use aoc_core::parse::Line;
+use aoc_core::ParseError;
 use regex::Regex;
 
 /// A valid `mul` operation is defined as __exactly__ "mul(" + up to 3 digits + "," + up to 3 digits + ")".
@@ -18,6 +19,7 @@ static DO_REGEX: LazyLock<Regex> =
 static DONT_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"don't\(\)").expect("`don't` regex should be a valid pattern"));
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Instruction {
     Mul(u16, u16),
     Do,
@@ -28,12 +30,13 @@ pub enum Instruction {
 ///
 /// Exists so that we can implement [`FromStr`] and parse a list of instructions from a string
 /// instead of a single one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Instructions(Vec<Instruction>);
 
-impl FromStr for Instructions {
-    type Err = Error;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+impl Instructions {
+    /// Parse every instruction in a line of corrupted memory, in order.
+    pub fn parse_line(line: Line) -> Result<Self, ParseError> {
+        let s = line.text;
         let dos = DO_REGEX
             .find_iter(s)
             .map(|val| Ok((val.start(), Instruction::Do)));
@@ -42,37 +45,35 @@ impl FromStr for Instructions {
             .find_iter(s)
             .map(|val| Ok((val.start(), Instruction::Dont)));
 
-        let muls = MUL_REGEX
-            .captures_iter(s)
-            .map(|cap| -> Result<_, Self::Err> {
-                let lhs = cap
-                    .get(1)
-                    .context("Capture should contain the LHS number")?;
-                let rhs = cap.get(2).expect("Capture should contain the RHS number");
-
-                let lhs_val = lhs
-                    .as_str()
-                    .parse()
-                    .context("LHS should be a valid integer")?;
-                let rhs_val = rhs
-                    .as_str()
-                    .parse()
-                    .context("RHS should be a valid integer")?;
-
-                Ok((lhs.start(), Instruction::Mul(lhs_val, rhs_val)))
-            });
+        let muls = MUL_REGEX.captures_iter(s).map(|cap| {
+            let lhs = cap.get(1).expect("Capture should contain the LHS number");
+            let rhs = cap.get(2).expect("Capture should contain the RHS number");
+
+            let lhs_val = line.parse(lhs.as_str())?;
+            let rhs_val = line.parse(rhs.as_str())?;
+
+            Ok((lhs.start(), Instruction::Mul(lhs_val, rhs_val)))
+        });
 
         let mut ops = dos
             .chain(donts)
             .chain(muls)
             .collect::<Result<Vec<_>, _>>()?;
 
-        ops.sort_by(|a, b| a.0.cmp(&b.0));
+        ops.sort_by_key(|a| a.0);
 
         Ok(Self(ops.into_iter().map(|(_, op)| op).collect()))
     }
 }
 
+impl FromStr for Instructions {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_line(Line::new(1, s))
+    }
+}
+
 /// Propagate inner Vec's iterator
 impl IntoIterator for Instructions {
     type Item = Instruction;
@@ -83,3 +84,13 @@ impl IntoIterator for Instructions {
         self.0.into_iter()
     }
 }
+
+impl<'a> IntoIterator for &'a Instructions {
+    type Item = &'a Instruction;
+
+    type IntoIter = std::slice::Iter<'a, Instruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
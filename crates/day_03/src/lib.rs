@@ -0,0 +1,140 @@
+mod generator;
+pub mod instruction;
+mod reference;
+
+pub use crate::instruction::{Instruction, Instructions};
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parse::Line;
+use aoc_core::stream::{self, StreamFn, StreamingSolution};
+use aoc_core::{Part, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+/// Day 3: Mull It Over
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Instructions>;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.lines())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_two(input))
+    }
+
+    fn streaming() -> Option<StreamFn> {
+        Some(stream::solve::<Self>)
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// Whether `mul` is enabled carries over between lines, so it is kept in the running tally.
+impl StreamingSolution for Day03 {
+    type Item = Instructions;
+
+    type State = Tally;
+
+    fn parse_line(line: Line) -> Result<Self::Item> {
+        Ok(Instructions::parse_line(line)?)
+    }
+
+    fn feed(part: Part, state: &mut Self::State, item: &Self::Item) -> Result<()> {
+        for instruction in item {
+            state.apply(instruction, part == Part::Two);
+        }
+        Ok(())
+    }
+
+    fn finish(_part: Part, state: Self::State) -> Result<impl Display> {
+        Ok(state.sum)
+    }
+}
+
+/// Running sum of `mul` results, and whether `mul` is currently enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tally {
+    pub sum: u64,
+    pub enabled: bool,
+}
+
+impl Default for Tally {
+    fn default() -> Self {
+        Self {
+            sum: 0,
+            enabled: true,
+        }
+    }
+}
+
+impl Tally {
+    /// Apply the next instruction. `do()` and `don't()` are only honoured if `conditional`.
+    pub fn apply(&mut self, instruction: &Instruction, conditional: bool) {
+        match instruction {
+            Instruction::Mul(lhs, rhs) => {
+                if self.enabled || !conditional {
+                    self.sum += u64::from(*lhs) * u64::from(*rhs);
+                }
+            }
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = false,
+        }
+    }
+}
+
+/// Parse the instructions on each line of the corrupted memory.
+pub fn parse_input<S: AsRef<str>>(input: impl IntoIterator<Item = S>) -> Result<Vec<Instructions>> {
+    input
+        .into_iter()
+        .enumerate()
+        .map(|(idx, text)| Ok(Instructions::parse_line(Line::new(idx + 1, text.as_ref()))?))
+        .collect()
+}
+
+fn tally(input: &[Instructions], conditional: bool) -> u64 {
+    let mut tally = Tally::default();
+    for instruction in input.iter().flatten() {
+        tally.apply(instruction, conditional);
+    }
+    tally.sum
+}
+
+/// Sum of the results of every `mul` instruction.
+pub fn part_one(input: &[Instructions]) -> u64 {
+    tally(input, false)
+}
+
+/// Sum of the results of the `mul` instructions enabled by the latest `do()` or `don't()`.
+pub fn part_two(input: &[Instructions]) -> u64 {
+    tally(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_core::fixture_tests!(crate::Day03);
+    aoc_core::generator_tests!(crate::Day03, size: 5, reference_size: 3);
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use aoc_core::differential::Reference;
+use aoc_core::Part;
+
+use crate::Day03;
+
+impl Reference for Day03 {
+    /// Scans the memory one character at a time for the start of an instruction, without
+    /// regexes.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let mut sum = 0;
+        let mut enabled = true;
+        let mut rest = raw_input;
+        while let Some(next) = rest.chars().next() {
+            if rest.starts_with("do()") {
+                enabled = true;
+            } else if rest.starts_with("don't()") {
+                enabled = false;
+            } else if let Some((lhs, rhs)) = mul(rest) {
+                if enabled || part == Part::One {
+                    sum += lhs * rhs;
+                }
+            }
+            rest = &rest[next.len_utf8()..];
+        }
+        Ok(sum.to_string())
+    }
+}
+
+/// Operands of the `mul` instruction at the start of `text`, if there is one.
+fn mul(text: &str) -> Option<(u64, u64)> {
+    let (lhs, rest) = number(text.strip_prefix("mul(")?)?;
+    let (rhs, rest) = number(rest.strip_prefix(',')?)?;
+    rest.starts_with(')').then_some((lhs, rhs))
+}
+
+/// The number of one to three digits at the start of `text`, and the text after it.
+fn number(text: &str) -> Option<(u64, &str)> {
+    let len = text.bytes().take_while(u8::is_ascii_digit).count();
+    if !(1..=3).contains(&len) {
+        return None;
+    }
+    Some((text[..len].parse().ok()?, &text[len..]))
+}
@@ -0,0 +1,17 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::Day04;
+
+impl Generator for Day04 {
+    /// A `size` by `size` word search of the letters of `XMAS`.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let letters = ['X', 'M', 'A', 'S'];
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size).map(|_| *rng.choose(&letters)).collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,103 @@
+mod generator;
+mod reference;
+
+use std::fmt::Display;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::{Direction, Grid, Point, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+/// Day 4: Ceres Search
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_two(input))
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// Parse the word search into a grid of letters.
+pub fn parse_input(input_reader: impl BufRead) -> Result<Grid<char>> {
+    let lines = input_reader.lines().collect::<Result<Vec<_>, _>>()?;
+    Grid::parse_lines(lines)
+}
+
+/// Whether the cells walked from `start` in `direction` spell out `word`, either forwards or
+/// backwards.
+pub fn spells(grid: &Grid<char>, start: Point, direction: Direction, word: &[char]) -> bool {
+    let found: Vec<_> = grid
+        .walk(start, direction)
+        .take(word.len())
+        .copied()
+        .collect();
+    found.iter().eq(word) || found.iter().eq(word.iter().rev())
+}
+
+/// Number of times `XMAS` appears in any direction.
+pub fn part_one(grid: &Grid<char>) -> usize {
+    const WORD: [char; 4] = ['X', 'M', 'A', 'S'];
+    // Words spelled backwards are matched too, so only directions going right or down are needed
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::DownRight,
+        Direction::DownLeft,
+    ];
+
+    grid.positions()
+        .map(|pos| {
+            DIRECTIONS
+                .iter()
+                .filter(|&&direction| spells(grid, pos, direction, &WORD))
+                .count()
+        })
+        .sum()
+}
+
+/// Number of times two diagonal `MAS` cross in the shape of an X.
+pub fn part_two(grid: &Grid<char>) -> usize {
+    const WORD: [char; 3] = ['M', 'A', 'S'];
+
+    // Each position is the top left corner of a 3x3 square with the two arms as its diagonals
+    grid.positions()
+        .filter(|&pos| {
+            spells(grid, pos, Direction::DownRight, &WORD)
+                && spells(grid, pos + Point::new(2, 0), Direction::DownLeft, &WORD)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_core::fixture_tests!(crate::Day04);
+    aoc_core::generator_tests!(crate::Day04, size: 30, reference_size: 12);
+}
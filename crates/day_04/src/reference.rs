@@ -0,0 +1,48 @@
+use anyhow::Result;
+use aoc_core::differential::Reference;
+use aoc_core::Part;
+
+use crate::Day04;
+
+impl Reference for Day04 {
+    /// Looks for the word starting from every letter in all eight directions, and for the
+    /// cross around every `A`, on plain rows of characters.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let rows: Vec<Vec<char>> = raw_input.lines().map(|row| row.chars().collect()).collect();
+        let at = |x: i64, y: i64| {
+            let row = rows.get(usize::try_from(y).ok()?)?;
+            row.get(usize::try_from(x).ok()?).copied()
+        };
+
+        let mut count = 0;
+        for (y, row) in rows.iter().enumerate() {
+            for x in 0..row.len() {
+                let (x, y) = (x as i64, y as i64);
+                match part {
+                    Part::One => {
+                        for dx in -1..=1 {
+                            for dy in -1..=1 {
+                                let spelled: String = (0..4)
+                                    .filter_map(|step| at(x + dx * step, y + dy * step))
+                                    .collect();
+                                if (dx, dy) != (0, 0) && spelled == "XMAS" {
+                                    count += 1;
+                                }
+                            }
+                        }
+                    }
+                    Part::Two => {
+                        let arm = |dx| {
+                            let ends = [at(x - dx, y - 1), at(x + dx, y + 1)];
+                            ends == [Some('M'), Some('S')] || ends == [Some('S'), Some('M')]
+                        };
+                        if at(x, y) == Some('A') && arm(1) && arm(-1) {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(count.to_string())
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::Day05;
+
+/// Longest update generated, so the reference can try every order of its pages.
+const MAX_UPDATE_LEN: u64 = 7;
+
+impl Generator for Day05 {
+    /// Rules between 10 to 25 distinct two digit pages, followed by `size` updates of an odd
+    /// number of those pages. The rules follow one order of the pages, but some inputs leave
+    /// out rules for some pairs, and some updates repeat a page. About half of the updates are
+    /// already in order.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut pages: Vec<u64> = (10..=99).collect();
+        rng.shuffle(&mut pages);
+        pages.truncate(rng.range(10..=25) as usize);
+
+        let coverage = *rng.choose(&[1.0, 0.7, 0.3]);
+        let mut rules = Vec::new();
+        for (idx, before) in pages.iter().enumerate() {
+            for after in &pages[idx + 1..] {
+                if rng.chance(coverage) {
+                    rules.push(format!("{before}|{after}\n"));
+                }
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let updates = (0..size).map(|_| {
+            let len = rng.range(0..=(MAX_UPDATE_LEN - 1) / 2) as usize * 2 + 1;
+            let mut picked: Vec<usize> = (0..pages.len()).collect();
+            rng.shuffle(&mut picked);
+            picked.truncate(len);
+            if len > 1 && rng.chance(0.1) {
+                picked[0] = picked[1];
+            }
+            if rng.chance(0.5) {
+                picked.sort();
+            }
+
+            let update: Vec<_> = picked.iter().map(|&idx| pages[idx].to_string()).collect();
+            update.join(",") + "\n"
+        });
+
+        rules.concat() + "\n" + &updates.collect::<String>()
+    }
+}
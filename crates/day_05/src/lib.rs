@@ -0,0 +1,316 @@
+mod generator;
+mod reference;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parse::Line;
+use aoc_core::{ParseError, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+/// * K = a given integer
+/// * V = set of integers that __CANNOT__ precede K
+pub type RuleMap = HashMap<u8, HashSet<u8>>;
+pub type PagesList = Vec<Vec<u8>>;
+
+/// The safety manual's page ordering rules and the updates to print.
+///
+/// Each part looks the rules up differently, so both representations are kept.
+pub struct Manual {
+    pub rules: RuleMap,
+    /// Every rule as a `(before, after)` pair.
+    pub ordering: HashSet<(u8, u8)>,
+    pub pages: PagesList,
+}
+
+impl Manual {
+    pub fn parse(raw_input: &str) -> Result<Self> {
+        let (rules, pages) = parse_input(raw_input)?;
+        let (ordering, _) = parse_input_two(raw_input)?;
+        Ok(Self {
+            rules,
+            ordering,
+            pages,
+        })
+    }
+}
+
+/// Day 5: Print Queue
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Manual;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Manual::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        Ok(part_one(&input.rules, &input.pages))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        part_two(&input.ordering, input.pages.clone())
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// Split the input into the lines of its rules section and of its updates section.
+fn sections(raw_input: &str) -> Result<(Vec<Line<'_>>, Vec<Line<'_>>)> {
+    let mut rules: Vec<_> = Line::numbered(raw_input).collect();
+    let blank = rules
+        .iter()
+        .position(|line| line.text.is_empty())
+        .context("Input not in two sections")?;
+    let pages = rules.split_off(blank + 1);
+    rules.pop();
+    Ok((rules, pages))
+}
+
+/// Parse a `before|after` rule.
+fn parse_rule(line: &Line) -> Result<(u8, u8), ParseError> {
+    let (before, after) = line
+        .text
+        .split_once('|')
+        .ok_or_else(|| line.error("Rule missing pipe divider"))?;
+    Ok((line.parse(before)?, line.parse(after)?))
+}
+
+fn parse_pages(lines: &[Line]) -> Result<PagesList, ParseError> {
+    lines
+        .iter()
+        .map(|line| line.text.split(',').map(|page| line.parse(page)).collect())
+        .collect()
+}
+
+/// Parse the rules into a [`RuleMap`], along with the page updates.
+pub fn parse_input(raw_input: &str) -> Result<(RuleMap, PagesList)> {
+    let (rules, pages) = sections(raw_input)?;
+
+    let mut parsed_rules = RuleMap::new();
+    for rule in &rules {
+        let (predicate, item) = parse_rule(rule)?;
+        parsed_rules.entry(predicate).or_default().insert(item);
+    }
+
+    Ok((parsed_rules, parse_pages(&pages)?))
+}
+
+/// 1. iterate through pages
+/// 1. iterate through each page
+/// 1. for each item in page:
+///    1. get the list of its value from the rules map
+///    1. get the subset of the page that comes before the current item
+///    1. if the subset contains any items from the list, the page is OUT OF ORDER
+///    1. Otherwise, it is IN ORDER: we find the middle value and add it to the cumulative sum
+pub fn part_one<O, I>(rules: &RuleMap, pages: O) -> usize
+where
+    O: IntoIterator<Item = I>,
+    I: AsRef<[u8]>,
+{
+    pages
+        .into_iter()
+        .filter_map(|page| {
+            let page = page.as_ref();
+            let mut good = true;
+
+            for (idx, item) in page.iter().enumerate() {
+                let Some(banned_items) = rules.get(item) else {
+                    continue;
+                };
+                for el in &page[..idx] {
+                    if banned_items.contains(el) {
+                        good = false;
+                        break;
+                    }
+                }
+            }
+
+            good.then_some(page[page.len() / 2] as usize)
+        })
+        .sum()
+}
+
+/// Parse the rules into `(before, after)` pairs, along with the page updates.
+pub fn parse_input_two(raw_input: &str) -> Result<(HashSet<(u8, u8)>, PagesList)> {
+    let (rules, pages) = sections(raw_input)?;
+
+    let parsed_rules = rules.iter().map(parse_rule).collect::<Result<_, _>>()?;
+
+    Ok((parsed_rules, parse_pages(&pages)?))
+}
+
+/// Sum of the middle pages of the updates that are out of order, once they are sorted.
+///
+/// Sorts those updates in place. Pages the rules don't order keep the order they had, and so do
+/// repeats of a page. Returns an error if the rules put the pages of an update in a cycle.
+pub fn part_two<O, I>(rules: &HashSet<(u8, u8)>, pages: O) -> Result<usize>
+where
+    O: IntoIterator<Item = I>,
+    I: AsMut<[u8]>,
+{
+    let mut sum = 0;
+    for mut page in pages {
+        let page = page.as_mut();
+        let sorted = sort_update(rules, page)?;
+        if sorted == page {
+            continue;
+        }
+        page.copy_from_slice(&sorted);
+
+        sum += page[page.len() / 2] as usize;
+    }
+    Ok(sum)
+}
+
+/// Order the pages of `update` by Kahn's algorithm: repeatedly take the first page that no
+/// other page left must come before.
+fn sort_update(rules: &HashSet<(u8, u8)>, update: &[u8]) -> Result<Vec<u8>> {
+    let must_precede = |before: usize, after: usize| {
+        before != after && rules.contains(&(update[before], update[after]))
+    };
+
+    // Number of pages left that must come before each page
+    let mut blockers: Vec<usize> = (0..update.len())
+        .map(|idx| {
+            (0..update.len())
+                .filter(|&other| must_precede(other, idx))
+                .count()
+        })
+        .collect();
+    let mut placed = vec![false; update.len()];
+    let mut sorted = Vec::with_capacity(update.len());
+
+    while sorted.len() < update.len() {
+        let Some(next) = (0..update.len()).find(|&idx| !placed[idx] && blockers[idx] == 0) else {
+            bail!("Rules put the pages of update {update:?} in a cycle");
+        };
+        placed[next] = true;
+        sorted.push(update[next]);
+        for idx in 0..update.len() {
+            if !placed[idx] && must_precede(next, idx) {
+                blockers[idx] -= 1;
+            }
+        }
+    }
+    Ok(sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::differential::Reference;
+    use aoc_core::generate::{Generator, Rng};
+    use aoc_core::{property, Part};
+
+    aoc_core::fixture_tests!(crate::Day05);
+    aoc_core::generator_tests!(crate::Day05, size: 30, reference_size: 12);
+
+    #[test]
+    fn test_part_two_follows_rules() {
+        let manual = |rng: &mut Rng| Day05::generate(rng.range(1..=20) as usize, rng);
+
+        property::check(100, manual, |raw_input| {
+            let manual = Manual::parse(raw_input).unwrap();
+            let mut pages = manual.pages.clone();
+            part_two(&manual.ordering, pages.iter_mut()).unwrap();
+
+            pages.iter().all(|update| {
+                update.iter().enumerate().all(|(idx, &before)| {
+                    update[idx + 1..]
+                        .iter()
+                        .all(|&after| !manual.ordering.contains(&(after, before)))
+                })
+            })
+        });
+    }
+
+    #[test]
+    fn test_part_two_keeps_unordered_pages() {
+        let manual = Manual::parse("3|1\n\n1,2,3\n2,5,2,1,3\n").unwrap();
+        let mut pages = manual.pages.clone();
+
+        assert_eq!(3 + 2, part_two(&manual.ordering, pages.iter_mut()).unwrap());
+        assert_eq!(vec![vec![2, 3, 1], vec![2, 5, 2, 3, 1]], pages);
+    }
+
+    #[test]
+    fn test_part_two_contradicting_rules() {
+        let manual = Manual::parse("1|2\n2|3\n3|1\n\n3,2,1\n").unwrap();
+
+        assert!(part_two(&manual.ordering, manual.pages).is_err());
+    }
+
+    /// Day 5 as it was when part two needed the rules to order every pair of pages of an update.
+    struct NeedsEveryRule;
+
+    impl Solution for NeedsEveryRule {
+        const DAY: u8 = 5;
+
+        type Input = Manual;
+
+        fn input_path() -> PathBuf {
+            Day05::input_path()
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Manual::parse(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Day05::part_one(input).map(|answer| answer.to_string())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<impl Display> {
+            let ordered =
+                |a: u8, b: u8| input.ordering.contains(&(a, b)) || input.ordering.contains(&(b, a));
+            for update in &input.pages {
+                for (idx, &a) in update.iter().enumerate() {
+                    if update[idx + 1..].iter().any(|&b| !ordered(a, b)) {
+                        bail!("Rules don't put the pages of update {update:?} in a single order");
+                    }
+                }
+            }
+            Day05::part_two(input).map(|answer| answer.to_string())
+        }
+    }
+
+    impl Generator for NeedsEveryRule {
+        fn generate(size: usize, rng: &mut Rng) -> String {
+            Day05::generate(size, rng)
+        }
+    }
+
+    impl Reference for NeedsEveryRule {
+        fn reference(part: Part, raw_input: &str) -> Result<String> {
+            Day05::reference(part, raw_input)
+        }
+    }
+
+    #[test]
+    fn test_harness_finds_unordered_pages() {
+        let divergence = differential::check::<NeedsEveryRule>(0..100, 12).unwrap();
+
+        assert_eq!(Part::Two, divergence.part);
+        assert!(divergence.actual.contains("single order"));
+    }
+}
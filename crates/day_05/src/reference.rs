@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use aoc_core::differential::Reference;
+use aoc_core::{Part, Solution};
+
+use crate::Day05;
+
+/// Longest update the reference tries every order of.
+const MAX_BRUTE_FORCE_LEN: usize = 7;
+
+impl Reference for Day05 {
+    /// Checks every pair of pages of an update against the rules, and puts an update that breaks
+    /// one in order by trying every order of its pages, keeping the first one that breaks none.
+    /// Orders are tried by the positions the pages had, so pages the rules don't order keep
+    /// their order.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let manual = Self::parse(raw_input)?;
+
+        let mut total = 0;
+        for update in &manual.pages {
+            let page = if follows_rules(&manual.ordering, update) {
+                (part == Part::One).then_some(update[update.len() / 2])
+            } else if part == Part::One {
+                None
+            } else {
+                Some(brute_force_order(&manual.ordering, update)?[update.len() / 2])
+            };
+            total += page.map_or(0, usize::from);
+        }
+        Ok(total.to_string())
+    }
+}
+
+/// Whether no page of `update` comes after a page that a rule puts after it.
+fn follows_rules(rules: &HashSet<(u8, u8)>, update: &[u8]) -> bool {
+    (0..update.len()).all(|before| {
+        (before + 1..update.len()).all(|after| !rules.contains(&(update[after], update[before])))
+    })
+}
+
+/// The first order of the pages of `update` that follows the rules, trying the orders of their
+/// positions from smallest to largest.
+fn brute_force_order(rules: &HashSet<(u8, u8)>, update: &[u8]) -> Result<Vec<u8>> {
+    if update.len() > MAX_BRUTE_FORCE_LEN {
+        bail!("Update {update:?} is too long to try every order of");
+    }
+
+    let mut positions: Vec<usize> = (0..update.len()).collect();
+    loop {
+        let order: Vec<u8> = positions.iter().map(|&idx| update[idx]).collect();
+        if follows_rules(rules, &order) {
+            return Ok(order);
+        }
+        if !next_permutation(&mut positions) {
+            bail!("No order of update {update:?} follows the rules");
+        }
+    }
+}
+
+/// Rearrange `items` into the next larger permutation, or return `false` if they are already in
+/// the largest one.
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(pivot) = (1..items.len())
+        .rev()
+        .find(|&idx| items[idx - 1] < items[idx])
+    else {
+        return false;
+    };
+    let swap = (pivot..items.len())
+        .rev()
+        .find(|&idx| items[idx] > items[pivot - 1])
+        .expect("The item after the pivot is larger");
+    items.swap(pivot - 1, swap);
+    items[pivot..].reverse();
+    true
+}
@@ -0,0 +1,34 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::{parse_input, patrol, Day06};
+
+impl Generator for Day06 {
+    /// A `size` by `size` map with about one obstacle in eight spaces and a guard facing a
+    /// random direction. `size` must be at least 1.
+    ///
+    /// Maps where the guard would patrol in a loop instead of leaving are thrown away, since
+    /// part one has no answer for them.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        assert!(size > 0, "Map needs room for the guard");
+        loop {
+            let mut map: Vec<Vec<char>> = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.chance(0.125) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect();
+            let (x, y) = (rng.index(size), rng.index(size));
+            map[y][x] = *rng.choose(&['^', '>', 'v', '<']);
+
+            let raw_map: String = map
+                .iter()
+                .map(|row| row.iter().chain(['\n'].iter()).collect::<String>())
+                .collect();
+            let parsed = parse_input(raw_map.as_bytes()).expect("Generated map should be valid");
+            if patrol(&parsed).is_ok() {
+                return raw_map;
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+mod generator;
+mod reference;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::BufRead,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error, Result};
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parallel;
+use aoc_core::{Direction, Grid, Point, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+/// Day 6: Guard Gallivant
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = GuardMap;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        part_two(input)
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// The lab map, with the guard at its starting position.
+pub type GuardMap = Grid<Space>;
+
+#[derive(Clone)]
+pub enum Space {
+    Empty,
+    Obstacle,
+    /// The guard's starting position, facing in the given direction.
+    Guard(Direction),
+}
+
+impl TryFrom<char> for Space {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        Ok(match value {
+            '.' => Self::Empty,
+            '#' => Self::Obstacle,
+            '^' => Self::Guard(Direction::Up),
+            '>' => Self::Guard(Direction::Right),
+            'v' => Self::Guard(Direction::Down),
+            '<' => Self::Guard(Direction::Left),
+            other => bail!("Invalid map character {other:?}"),
+        })
+    }
+}
+
+/// Parse the lab map.
+pub fn parse_input(raw_input: impl BufRead) -> Result<GuardMap> {
+    let lines = raw_input.lines().collect::<Result<Vec<_>, _>>()?;
+    Grid::parse_lines(lines)
+}
+
+/// Find the starting position and orientation of the guard.
+pub fn find_guard(input: &GuardMap) -> Result<(Point, Direction)> {
+    input
+        .iter()
+        .find_map(|(pos, space)| match space {
+            Space::Guard(guard) => Some((pos, *guard)),
+            _ => None,
+        })
+        .context("No guard found in input map")
+}
+
+/// Every position the guard visits before leaving the map, including its starting position.
+///
+/// Returns an error if the guard patrols in a loop instead of leaving.
+pub fn patrol(input: &GuardMap) -> Result<HashSet<Point>> {
+    let mut visited = HashSet::<Point>::new();
+    let mut seen = HashSet::<(Point, Direction)>::new();
+    let (mut pos, mut guard) = find_guard(input)?;
+    visited.insert(pos);
+
+    while let Some(next_pos) = input.step(pos, guard) {
+        if !seen.insert((pos, guard)) {
+            bail!("Guard patrols in a loop and never leaves the map");
+        }
+        match &input[next_pos] {
+            Space::Obstacle => guard = guard.rotate_right(),
+            _ => {
+                pos = next_pos;
+                visited.insert(pos);
+            }
+        }
+    }
+
+    Ok(visited)
+}
+
+/// Number of distinct positions the guard visits.
+pub fn part_one(input: &GuardMap) -> Result<usize> {
+    Ok(patrol(input)?.len())
+}
+
+/// Number of positions where a single new obstacle would trap the guard in a loop.
+pub fn part_two(input: &GuardMap) -> Result<usize> {
+    let (starting_pos, starting_guard) = find_guard(input)?;
+
+    // We would only try to place obstacles along the guard's path
+    let mut possible_positions = patrol(input)?;
+    // We can't place an obstacle at the starting position
+    possible_positions.remove(&starting_pos);
+    let possible_positions: Vec<_> = possible_positions.into_iter().collect();
+
+    let loops = parallel::map(&possible_positions, |&obs_pos| {
+        loops_with_obstacle(input, starting_pos, starting_guard, obs_pos)
+    });
+    Ok(loops.into_iter().filter(|&looped| looped).count())
+}
+
+/// Whether the guard, starting at `guard_pos` facing `guard`, patrols in a loop once a new
+/// obstacle is placed at `obs_pos`.
+fn loops_with_obstacle(
+    input: &GuardMap,
+    mut guard_pos: Point,
+    mut guard: Direction,
+    obs_pos: Point,
+) -> bool {
+    // Maps the position of the guard to the orientation of the guard when it was previously at that position.
+    // Used to check for loops - if the guard is at a position it already visited and in the same orientation,
+    // then it's in a loop.
+    let mut visited: HashMap<Point, HashSet<Direction>> = HashMap::new();
+
+    while let Some(next_pos) = input.step(guard_pos, guard) {
+        // Turns are recorded too, or a guard boxed in by obstacles would spin forever
+        if !visited.entry(guard_pos).or_default().insert(guard) {
+            return true;
+        }
+        match &input[next_pos] {
+            _ if next_pos == obs_pos => guard = guard.rotate_right(),
+            Space::Obstacle => guard = guard.rotate_right(),
+            _ => guard_pos = next_pos,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::generate::{Generator, Rng};
+    use aoc_core::property;
+
+    aoc_core::fixture_tests!(crate::Day06);
+    aoc_core::generator_tests!(crate::Day06, size: 30, reference_size: 12);
+
+    #[test]
+    fn test_visits_at_most_free_spaces() {
+        let map = |rng: &mut Rng| Day06::generate(rng.range(1..=15) as usize, rng);
+
+        property::check(100, map, |raw_map| {
+            let map = parse_input(raw_map.as_bytes()).unwrap();
+            let free = map
+                .iter()
+                .filter(|(_, space)| !matches!(space, Space::Obstacle))
+                .count();
+            part_one(&map).unwrap() <= free
+        });
+    }
+
+    #[test]
+    fn test_patrol_loop_is_an_error() {
+        let map = parse_input(".#..\n...#\n#^..\n..#.\n".as_bytes()).unwrap();
+
+        assert!(patrol(&map).is_err());
+    }
+}
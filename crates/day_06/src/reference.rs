@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use aoc_core::differential::Reference;
+use aoc_core::Part;
+
+use crate::Day06;
+
+impl Reference for Day06 {
+    /// Walks the guard over plain rows of characters, and for part two tries an obstacle on
+    /// every empty space of the map.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let mut map: Vec<Vec<char>> = raw_input.lines().map(|row| row.chars().collect()).collect();
+
+        let guards: Vec<_> = map
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, &space)| (x, y, space)))
+            .filter_map(|(x, y, space)| {
+                let facing = match space {
+                    '^' => (0, -1),
+                    '>' => (1, 0),
+                    'v' => (0, 1),
+                    '<' => (-1, 0),
+                    _ => return None,
+                };
+                Some(((x as i64, y as i64), facing))
+            })
+            .collect();
+        let &[(start, facing)] = guards.as_slice() else {
+            bail!("Map should have exactly one guard, found {}", guards.len());
+        };
+        let visited = walk(&map, start, facing).context("Guard patrols in a loop")?;
+
+        let answer = match part {
+            Part::One => visited.len(),
+            Part::Two => {
+                let mut loops = 0;
+                for y in 0..map.len() {
+                    for x in 0..map[y].len() {
+                        if map[y][x] != '.' {
+                            continue;
+                        }
+                        map[y][x] = '#';
+                        loops += usize::from(walk(&map, start, facing).is_none());
+                        map[y][x] = '.';
+                    }
+                }
+                loops
+            }
+        };
+        Ok(answer.to_string())
+    }
+}
+
+/// Every position the guard visits before leaving `map`, or `None` if it patrols in a loop.
+fn walk(map: &[Vec<char>], start: (i64, i64), facing: (i64, i64)) -> Option<HashSet<(i64, i64)>> {
+    let at = |(x, y): (i64, i64)| {
+        let row = map.get(usize::try_from(y).ok()?)?;
+        row.get(usize::try_from(x).ok()?).copied()
+    };
+
+    let (mut pos, mut facing) = (start, facing);
+    let mut seen = HashSet::new();
+    while seen.insert((pos, facing)) {
+        let next = (pos.0 + facing.0, pos.1 + facing.1);
+        match at(next) {
+            None => return Some(seen.into_iter().map(|(pos, _)| pos).collect()),
+            // Turn right, with y growing downwards
+            Some('#') => facing = (-facing.1, facing.0),
+            Some(_) => pos = next,
+        }
+    }
+    None
+}
@@ -0,0 +1,40 @@
+use aoc_core::generate::{Generator, Rng};
+
+use crate::math::{Op2, Operator, Variants};
+use crate::Day07;
+
+impl Generator for Day07 {
+    /// `size` equations, most of 2 to 6 numbers below 100. Some are longer, with up to 10 numbers
+    /// below 1000, and some have numbers up to 10^16, so that combining them can overflow. Most
+    /// test values come from combining the numbers with random operators, the rest are a little
+    /// off so the equation can't be solved. Test values are small enough that their sum fits in a
+    /// `usize`.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let operators: Vec<Op2> = Op2::variants().collect();
+        let max_target = usize::MAX / (size.max(1) * 2);
+        (0..size)
+            .map(|_| {
+                let (len, max) = match rng.index(10) {
+                    0 => (rng.range(7..=10), 999),
+                    1 => (rng.range(2..=4), 10u64.pow(16)),
+                    _ => (rng.range(2..=6), 99),
+                };
+                let nums: Vec<usize> = (0..len).map(|_| rng.range(1..=max) as usize).collect();
+                // Combinations too large to be a test value get a random one instead
+                let mut target = nums[1..]
+                    .iter()
+                    .try_fold(nums[0], |lhs, &rhs| {
+                        rng.choose(&operators).operate(lhs, rhs)
+                    })
+                    .filter(|&target| target <= max_target)
+                    .unwrap_or_else(|| rng.range(1..=max_target as u64) as usize);
+                if rng.chance(0.3) {
+                    target += rng.range(1..=10) as usize;
+                }
+
+                let nums: Vec<_> = nums.iter().map(usize::to_string).collect();
+                format!("{target}: {}\n", nums.join(" "))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,313 @@
+mod generator;
+pub mod math;
+mod reference;
+
+use crate::math::{Op, Op2, Operator, Variants};
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::differential::{self, DiffFn};
+use aoc_core::generate::{self, GenerateFn};
+use aoc_core::parallel;
+use aoc_core::parse::Line;
+use aoc_core::stream::{self, StreamFn, StreamingSolution};
+use aoc_core::{ParseError, Part, Solution};
+
+const INPUT_FILE: &str = "input.txt";
+
+/// An equation's test value and the numbers to combine into it.
+pub type InputLine = (usize, Vec<usize>);
+
+/// Day 7: Bridge Repair
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<InputLine>;
+
+    fn input_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(INPUT_FILE)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input.as_bytes())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display> {
+        part_two(input)
+    }
+
+    fn streaming() -> Option<StreamFn> {
+        Some(stream::solve::<Self>)
+    }
+
+    fn generator() -> Option<GenerateFn> {
+        Some(generate::input::<Self>)
+    }
+
+    fn differential() -> Option<DiffFn> {
+        Some(differential::check::<Self>)
+    }
+}
+
+/// Every equation is checked on its own, so each part just sums the solvable ones seen so far.
+impl StreamingSolution for Day07 {
+    type Item = InputLine;
+
+    type State = usize;
+
+    fn parse_line(line: Line) -> Result<Self::Item> {
+        Ok(parse_equation(line)?)
+    }
+
+    fn feed(part: Part, state: &mut Self::State, (target_value, nums): &Self::Item) -> Result<()> {
+        let solvable = match part {
+            Part::One => is_solvable::<Op>(*target_value, nums)?,
+            Part::Two => is_solvable::<Op2>(*target_value, nums)?,
+        };
+        if solvable {
+            *state = state
+                .checked_add(*target_value)
+                .context("Sum of test values overflows")?;
+        }
+        Ok(())
+    }
+
+    fn finish(_part: Part, state: Self::State) -> Result<impl Display> {
+        Ok(state)
+    }
+}
+
+/// Parse each line into an equation.
+pub fn parse_input(input_reader: impl BufRead) -> Result<Vec<InputLine>> {
+    input_reader
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Ok(parse_equation(Line::new(idx + 1, &text?))?))
+        .collect()
+}
+
+/// Parse one line into an equation.
+pub fn parse_equation(line: Line) -> Result<InputLine, ParseError> {
+    let (test_value, equation) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.error_at_end("Equation should be split via colon"))?;
+
+    let equation: Vec<_> = equation
+        .split_whitespace()
+        .map(|el| line.parse(el))
+        .collect::<Result<_, _>>()?;
+    if equation.is_empty() {
+        return Err(line.error_at_end("Equation has no numbers"));
+    }
+
+    Ok((line.parse(test_value)?, equation))
+}
+
+/// Sum of the test values of the equations that can be made true using only add and multiply.
+pub fn part_one(input: &[InputLine]) -> Result<usize> {
+    solve::<Op>(input)
+}
+
+/// Sum of the test values of the equations that can be made true using add, multiply and
+/// concatenate.
+pub fn part_two(input: &[InputLine]) -> Result<usize> {
+    solve::<Op2>(input)
+}
+
+/// Sum of the test values of the equations that some combination of `Op` operators makes true.
+///
+/// Returns the error of the first equation that fails, see [`is_solvable`], or an error if the
+/// sum overflows.
+pub fn solve<Op>(input: &[InputLine]) -> Result<usize>
+where
+    Op: Operator + Variants,
+{
+    let solvable = parallel::map(input, |(target_value, nums)| {
+        is_solvable::<Op>(*target_value, nums)
+    });
+
+    let mut sum: usize = 0;
+    for ((target_value, _), solvable) in input.iter().zip(solvable) {
+        if solvable? {
+            sum = sum
+                .checked_add(*target_value)
+                .context("Sum of test values overflows")?;
+        }
+    }
+
+    Ok(sum)
+}
+
+/// Whether some combination of `Op` operators between `nums` evaluates to `target_value`.
+///
+/// Combinations that overflow a `usize` don't count as matching, since no test value is that
+/// large. Returns an error if `nums` is empty.
+pub fn is_solvable<Op>(target_value: usize, nums: &[usize]) -> Result<bool>
+where
+    Op: Operator + Variants,
+{
+    let Some((&first, rest)) = nums.split_first() else {
+        bail!("Equation has no numbers");
+    };
+    Ok(reaches::<Op>(
+        target_value,
+        first,
+        rest,
+        &mut HashSet::new(),
+    ))
+}
+
+/// Whether combining `acc` with `rest` from the left can evaluate to `target_value`.
+///
+/// No operator makes a value smaller except multiplying by zero, so a branch stops once it
+/// passes `target_value` unless a zero is still to come. Different branches often reach the same
+/// value, so `dead_ends` remembers the `(numbers left, value)` pairs that can't match.
+fn reaches<Op>(
+    target_value: usize,
+    acc: usize,
+    rest: &[usize],
+    dead_ends: &mut HashSet<(usize, usize)>,
+) -> bool
+where
+    Op: Operator + Variants,
+{
+    let Some((&num, rest)) = rest.split_first() else {
+        return acc == target_value;
+    };
+    if dead_ends.contains(&(rest.len(), acc)) {
+        return false;
+    }
+
+    let found = Op::variants().any(|op| {
+        op.operate(acc, num).is_some_and(|next| {
+            (next <= target_value || rest.contains(&0))
+                && reaches::<Op>(target_value, next, rest, dead_ends)
+        })
+    });
+    if !found {
+        dead_ends.insert((rest.len(), acc));
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::differential::Reference;
+    use aoc_core::generate::{Generator, Rng};
+
+    aoc_core::fixture_tests!(crate::Day07);
+    aoc_core::generator_tests!(crate::Day07, size: 50, reference_size: 20);
+
+    #[test]
+    fn test_empty_equation() {
+        assert!(parse_equation(Line::new(1, "5:")).is_err());
+        assert!(is_solvable::<Op>(5, &[]).is_err());
+    }
+
+    #[test]
+    fn test_long_equations() {
+        let mut nums = vec![1; 40];
+        nums.push(7);
+
+        assert!(is_solvable::<Op>(47, &nums).unwrap());
+        assert!(!is_solvable::<Op>(48, &nums).unwrap());
+        assert!(!is_solvable::<Op2>(5, &[0; 40]).unwrap());
+    }
+
+    #[test]
+    fn test_sum_overflow_is_an_error() {
+        let input = "18446744073709551615: 18446744073709551615\n1: 1\n";
+
+        assert!(part_one(&parse_input(input.as_bytes()).unwrap()).is_err());
+        let outcomes = stream::solve::<Day07>(&mut input.as_bytes(), &[Part::One]).unwrap();
+        assert!(outcomes[0].answer.is_err());
+    }
+
+    #[test]
+    fn test_overflow_does_not_match() {
+        let input = "2240000: 100 200 300 400 500 600 700 800\n\
+                     1000000000000000010: 1000000000000000000 10 1\n";
+        let input = parse_input(input.as_bytes()).unwrap();
+
+        assert!(!is_solvable::<Op2>(3, &[usize::MAX, 2]).unwrap());
+        assert_eq!(2240000 + 1000000000000000010, part_one(&input).unwrap());
+        assert_eq!(2240000 + 1000000000000000010, part_two(&input).unwrap());
+    }
+
+    /// Day 7 as it was when a combination that overflows failed the whole part.
+    struct FailsOnOverflow;
+
+    impl FailsOnOverflow {
+        fn solve(input: &[InputLine], operators: &[Op2]) -> Result<usize> {
+            let mut sum = 0;
+            for (target_value, nums) in input {
+                let mut values = vec![nums[0]];
+                for &num in &nums[1..] {
+                    values = values
+                        .iter()
+                        .flat_map(|&acc| operators.iter().map(move |op| op.operate(acc, num)))
+                        .collect::<Option<_>>()
+                        .context("Combination overflows")?;
+                }
+                if values.contains(target_value) {
+                    sum += target_value;
+                }
+            }
+            Ok(sum)
+        }
+    }
+
+    impl Solution for FailsOnOverflow {
+        const DAY: u8 = 7;
+
+        type Input = Vec<InputLine>;
+
+        fn input_path() -> PathBuf {
+            Day07::input_path()
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Day07::parse(input)
+        }
+
+        fn part_one(input: &Self::Input) -> Result<impl Display> {
+            Self::solve(input, &Op::variants().map(Op2::Base).collect::<Vec<_>>())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<impl Display> {
+            Self::solve(input, &Op2::variants().collect::<Vec<_>>())
+        }
+    }
+
+    impl Generator for FailsOnOverflow {
+        fn generate(size: usize, rng: &mut Rng) -> String {
+            Day07::generate(size, rng)
+        }
+    }
+
+    impl Reference for FailsOnOverflow {
+        fn reference(part: Part, raw_input: &str) -> Result<String> {
+            Day07::reference(part, raw_input)
+        }
+    }
+
+    #[test]
+    fn test_harness_finds_overflow_errors() {
+        let divergence = differential::check::<FailsOnOverflow>(0..100, 20).unwrap();
+
+        assert!(divergence.actual.contains("Combination overflows"));
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::VecDeque;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 use std::marker::PhantomData;
+use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Error, Result};
 use itertools::Itertools;
 
 pub trait Variants {
@@ -11,8 +12,9 @@ pub trait Variants {
 
 pub trait Operator {
     /// Perform the operation described by the associated operator, using provided integer
-    /// values for the left hand side and right hand side. Returns the result of the operation.
-    fn operate(&self, lhs: usize, rhs: usize) -> usize;
+    /// values for the left hand side and right hand side. Returns the result of the operation,
+    /// or `None` if it overflows.
+    fn operate(&self, lhs: usize, rhs: usize) -> Option<usize>;
 }
 
 #[derive(Clone, Copy)]
@@ -28,10 +30,10 @@ impl Variants for Op {
 }
 
 impl Operator for Op {
-    fn operate(&self, lhs: usize, rhs: usize) -> usize {
+    fn operate(&self, lhs: usize, rhs: usize) -> Option<usize> {
         match self {
-            Self::Add => lhs + rhs,
-            Self::Mult => lhs * rhs,
+            Self::Add => lhs.checked_add(rhs),
+            Self::Mult => lhs.checked_mul(rhs),
         }
     }
 }
@@ -49,6 +51,18 @@ impl Display for Op {
     }
 }
 
+impl FromStr for Op {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "+" => Self::Add,
+            "*" => Self::Mult,
+            other => bail!("Unknown operator {other:?}"),
+        })
+    }
+}
+
 impl Debug for Op {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{self}")
@@ -70,13 +84,13 @@ impl Variants for Op2 {
 }
 
 impl Operator for Op2 {
-    fn operate(&self, lhs: usize, rhs: usize) -> usize {
+    fn operate(&self, lhs: usize, rhs: usize) -> Option<usize> {
         match self {
             Op2::Base(op) => op.operate(lhs, rhs),
             Op2::Concat => {
                 let right_digits = rhs.checked_ilog10().map(|x| x + 1).unwrap_or(1);
-                let output = lhs * 10_usize.pow(right_digits);
-                output + rhs
+                let output = lhs.checked_mul(10_usize.checked_pow(right_digits)?)?;
+                output.checked_add(rhs)
             }
         }
     }
@@ -95,6 +109,17 @@ impl Display for Op2 {
     }
 }
 
+impl FromStr for Op2 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "||" => Ok(Op2::Concat),
+            other => other.parse().map(Op2::Base),
+        }
+    }
+}
+
 impl Debug for Op2 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}", self)
@@ -121,12 +146,15 @@ impl<Op, Ops> Expression<Op, Ops> {
 impl<Op: Operator, Ops: AsRef<[Op]>> Expression<Op, Ops> {
     /// Evaluate the expression from right to left, using only the order of operators as precedence.
     ///
-    /// Returns the value of the expression, or an error if the expression is not valid.
+    /// Returns the value of the expression, or an error if the expression is not valid or its
+    /// value overflows.
     pub fn evaluate(&mut self) -> Result<usize> {
         for op in self.operators.as_ref() {
             let lhs = self.nums.pop_front().context("Missing LHS")?;
             let rhs = self.nums.pop_front().context("Missing RHS")?;
-            let eval = op.operate(lhs, rhs);
+            let eval = op
+                .operate(lhs, rhs)
+                .with_context(|| format!("Combining {lhs} and {rhs} overflows"))?;
             self.nums.push_front(eval);
         }
 
@@ -150,31 +178,29 @@ impl<Op: Display, Ops: AsRef<[Op]>> Display for Expression<Op, Ops> {
     }
 }
 
-pub trait Ordering {
-    /// Generate all possible orderings for operators where `num` is the number of operators.
-    fn orderings(num: usize) -> Vec<Vec<Self>>
-    where
-        Self: Sized;
-}
+/// Parses the form written by [`Display`]: numbers and operators separated by spaces.
+impl<Op: FromStr<Err = Error>> FromStr for Expression<Op, Vec<Op>> {
+    type Err = Error;
 
-impl<T> Ordering for T
-where
-    T: Variants + Copy,
-{
-    fn orderings(num: usize) -> Vec<Vec<Self>> {
-        if num == 0 {
-            return vec![vec![]];
+    fn from_str(s: &str) -> Result<Self> {
+        let mut nums = VecDeque::new();
+        let mut operators = Vec::new();
+        for (idx, token) in s.split_whitespace().enumerate() {
+            if idx % 2 == 0 {
+                nums.push_back(
+                    token
+                        .parse()
+                        .with_context(|| format!("Invalid number {token:?}"))?,
+                );
+            } else {
+                operators.push(token.parse()?);
+            }
         }
 
-        let mut orderings = vec![];
-        for el in Self::variants() {
-            let sub_orderings = Self::orderings(num - 1);
-            for mut sub in sub_orderings {
-                sub.push(el);
-                orderings.push(sub);
-            }
+        if nums.len() != operators.len() + 1 {
+            bail!("Expression should start and end with a number");
         }
-        orderings
+        Ok(Self::new(nums, operators))
     }
 }
 
@@ -182,6 +208,8 @@ where
 mod tests {
     use super::*;
 
+    use aoc_core::generate::Rng;
+    use aoc_core::property;
     use rstest::rstest;
 
     #[rstest]
@@ -197,4 +225,34 @@ mod tests {
         let mut expression = Expression::new(nums.into(), ops.into());
         assert_eq!(expected, expression.evaluate().unwrap());
     }
+
+    #[test]
+    fn expression_display_round_trip() {
+        let parts = |rng: &mut Rng| {
+            let operators: Vec<Op2> = Op2::variants().collect();
+            // Each number or operator adds at most 3 digits, so 6 numbers can't overflow
+            let nums: Vec<usize> = (0..rng.range(1..=6))
+                .map(|_| rng.range(0..=999) as usize)
+                .collect();
+            let ops: Vec<Op2> = (1..nums.len()).map(|_| *rng.choose(&operators)).collect();
+            (nums, ops)
+        };
+
+        property::check(200, parts, |(nums, ops)| {
+            let mut expression = Expression::new(nums.clone().into(), ops.clone());
+            let written = expression.to_string();
+            let mut parsed: Expression<Op2, Vec<Op2>> = written.parse().unwrap();
+
+            parsed.to_string() == written
+                && parsed.evaluate().unwrap() == expression.evaluate().unwrap()
+        });
+    }
+
+    #[test]
+    fn expression_parse_errors() {
+        assert!("".parse::<Expression<Op, Vec<Op>>>().is_err());
+        assert!("1 +".parse::<Expression<Op, Vec<Op>>>().is_err());
+        assert!("1 || 2".parse::<Expression<Op, Vec<Op>>>().is_err());
+        assert!("1 || 2".parse::<Expression<Op2, Vec<Op2>>>().is_ok());
+    }
 }
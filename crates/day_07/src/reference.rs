@@ -0,0 +1,40 @@
+use anyhow::{bail, Context, Result};
+use aoc_core::differential::Reference;
+use aoc_core::{Part, Solution};
+
+use crate::Day07;
+
+impl Reference for Day07 {
+    /// Tries every choice of operator recursively, from the left, dropping the choices that
+    /// overflow.
+    fn reference(part: Part, raw_input: &str) -> Result<String> {
+        let mut total = 0;
+        for (target, nums) in Self::parse(raw_input)? {
+            let Some((&first, rest)) = nums.split_first() else {
+                bail!("Equation for {target} has no numbers");
+            };
+            if reachable(first, rest, part == Part::Two).contains(&target) {
+                total = target
+                    .checked_add(total)
+                    .context("Sum of test values overflows")?;
+            }
+        }
+        Ok(total.to_string())
+    }
+}
+
+/// Every value `acc` combined with `rest` can make without overflowing.
+fn reachable(acc: usize, rest: &[usize], concat: bool) -> Vec<usize> {
+    let Some((&num, rest)) = rest.split_first() else {
+        return vec![acc];
+    };
+    let mut next = vec![acc.checked_add(num), acc.checked_mul(num)];
+    if concat {
+        next.push(format!("{acc}{num}").parse().ok());
+    }
+
+    next.into_iter()
+        .flatten()
+        .flat_map(|acc| reachable(acc, rest, concat))
+        .collect()
+}